@@ -0,0 +1,68 @@
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+// Generates every read swizzle (`xy`, `zyx`, `wwww`, ...) and every write
+// swizzle with distinct components (`set_xz`, `set_wzy`, ...) for Vec2, Vec3 and Vec4.
+
+const COMPONENTS: [char; 4] = ['x', 'y', 'z', 'w'];
+
+fn vec_name(len: usize) -> &'static str {
+    match len {
+        2 => "Vec2",
+        3 => "Vec3",
+        4 => "Vec4",
+        _ => unreachable!(),
+    }
+}
+
+fn combinations(comps: &[char], len: usize) -> Vec<Vec<char>> {
+    if len == 0 {
+        return vec![Vec::new()];
+    }
+    let mut out = Vec::new();
+    for rest in combinations(comps, len - 1) {
+        for c in comps {
+            let mut combo = rest.clone();
+            combo.push(*c);
+            out.push(combo);
+        }
+    }
+    out
+}
+
+fn main() {
+    let mut code = String::new();
+
+    for size in 2..=4 {
+        let comps = &COMPONENTS[..size];
+        writeln!(code, "impl {} {{", vec_name(size)).unwrap();
+
+        for len in 2..=4 {
+            let target = vec_name(len);
+            let fields = ["x", "y", "z", "w"];
+            for combo in combinations(comps, len) {
+                let name: String = combo.iter().collect();
+                let args: Vec<String> = combo.iter().enumerate()
+                    .map(|(i, c)| format!("{}: self.{}", fields[i], c))
+                    .collect();
+                writeln!(code, "    #[inline]\n    pub fn {}(&self) -> {} {{\n        {} {{ {} }}\n    }}\n", name, target, target, args.join(", ")).unwrap();
+
+                let distinct = combo.iter().enumerate().all(|(i, c)| !combo[..i].contains(c));
+                if distinct && len <= size {
+                    let assigns: Vec<String> = combo.iter().enumerate()
+                        .map(|(i, c)| format!("self.{} = v.{};", c, fields[i]))
+                        .collect();
+                    writeln!(code, "    #[inline]\n    pub fn set_{}(&mut self, v: {}) {{\n        {}\n    }}\n", name, target, assigns.join(" ")).unwrap();
+                }
+            }
+        }
+
+        writeln!(code, "}}\n").unwrap();
+    }
+
+    let dest = Path::new(&env::var("OUT_DIR").unwrap()).join("swizzles.rs");
+    fs::write(dest, code).unwrap();
+    println!("cargo:rerun-if-changed=build.rs");
+}
@@ -0,0 +1,164 @@
+use crate::vector::{Vec2, Vec3, Vec4};
+use crate::point::{Point2, Point3, Point4};
+use crate::matrix::{Mat2, Mat3, Mat4};
+use crate::quaternion::Quat;
+
+// ##################################################
+// #                   TOLERANCES                   #
+// ##################################################
+
+/// Absolute tolerance used by `approx_eq` and the assertion macros
+pub const DEFAULT_EPSILON: f32 = 1e-5;
+
+/// Relative tolerance used by `approx_eq` and the assertion macros
+pub const DEFAULT_MAX_RELATIVE: f32 = 1e-5;
+
+/// Distance in units in the last place used by `assert_ulps_eq!`
+pub const DEFAULT_MAX_ULPS: u32 = 4;
+
+// ##################################################
+// #               APPROXIMATE EQUALITY             #
+// ##################################################
+
+/// Floating point comparison with a tolerance.
+/// Composite types compare component-wise and are equal if every component is.
+pub trait ApproxEq {
+    /// `|a - b| <= epsilon`
+    fn abs_diff_eq(&self, other: &Self, epsilon: f32) -> bool;
+
+    /// Equal if the difference is within `epsilon` or within `max_relative` times the larger magnitude
+    fn relative_eq(&self, other: &Self, epsilon: f32, max_relative: f32) -> bool;
+
+    /// Equal if the difference is within `epsilon` or the floats are at most `max_ulps` representable values apart
+    fn ulps_eq(&self, other: &Self, epsilon: f32, max_ulps: u32) -> bool;
+
+    /// Relative comparison with the default tolerances
+    fn approx_eq(&self, other: &Self) -> bool {
+        self.relative_eq(other, DEFAULT_EPSILON, DEFAULT_MAX_RELATIVE)
+    }
+}
+
+impl ApproxEq for f32 {
+    fn abs_diff_eq(&self, other: &Self, epsilon: f32) -> bool {
+        self == other || (self - other).abs() <= epsilon
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: f32, max_relative: f32) -> bool {
+        if self == other {
+            return true;
+        }
+        if self.is_infinite() || other.is_infinite() {
+            return false;
+        }
+
+        let diff = (self - other).abs();
+        if diff <= epsilon {
+            return true;
+        }
+        diff <= self.abs().max(other.abs()) * max_relative
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: f32, max_ulps: u32) -> bool {
+        if self.abs_diff_eq(other, epsilon) {
+            return true;
+        }
+        if self.is_nan() || other.is_nan() || self.is_sign_negative() != other.is_sign_negative() {
+            return false;
+        }
+        self.to_bits().abs_diff(other.to_bits()) <= max_ulps
+    }
+}
+
+macro_rules! impl_approx_eq {
+    ($name:ty, |$v:ident| [$($component:expr),+]) => {
+        impl ApproxEq for $name {
+            fn abs_diff_eq(&self, other: &Self, epsilon: f32) -> bool {
+                let a = { let $v = self; [$($component),+] };
+                let b = { let $v = other; [$($component),+] };
+                a.iter().zip(b.iter()).all(|(a, b)| a.abs_diff_eq(b, epsilon))
+            }
+
+            fn relative_eq(&self, other: &Self, epsilon: f32, max_relative: f32) -> bool {
+                let a = { let $v = self; [$($component),+] };
+                let b = { let $v = other; [$($component),+] };
+                a.iter().zip(b.iter()).all(|(a, b)| a.relative_eq(b, epsilon, max_relative))
+            }
+
+            fn ulps_eq(&self, other: &Self, epsilon: f32, max_ulps: u32) -> bool {
+                let a = { let $v = self; [$($component),+] };
+                let b = { let $v = other; [$($component),+] };
+                a.iter().zip(b.iter()).all(|(a, b)| a.ulps_eq(b, epsilon, max_ulps))
+            }
+        }
+    };
+}
+
+impl_approx_eq!(Vec2, |v| [v.x, v.y]);
+impl_approx_eq!(Vec3, |v| [v.x, v.y, v.z]);
+impl_approx_eq!(Vec4, |v| [v.x, v.y, v.z, v.w]);
+impl_approx_eq!(Point2, |p| [p.x, p.y]);
+impl_approx_eq!(Point3, |p| [p.x, p.y, p.z]);
+impl_approx_eq!(Point4, |p| [p.x, p.y, p.z, p.w]);
+impl_approx_eq!(Quat, |q| [q.s, q.v.x, q.v.y, q.v.z]);
+impl_approx_eq!(Mat2, |m| [m.x00, m.x01, m.x10, m.x11]);
+impl_approx_eq!(Mat3, |m| [m.x00, m.x01, m.x02, m.x10, m.x11, m.x12, m.x20, m.x21, m.x22]);
+impl_approx_eq!(Mat4, |m| [m.x00, m.x01, m.x02, m.x03, m.x10, m.x11, m.x12, m.x13, m.x20, m.x21, m.x22, m.x23, m.x30, m.x31, m.x32, m.x33]);
+
+// ##################################################
+// #               ASSERTION MACROS                 #
+// ##################################################
+
+/// Asserts that two values are equal up to a relative tolerance,
+/// optionally passing `epsilon` and `max_relative` explicitly.
+#[macro_export]
+macro_rules! assert_approx_eq {
+    ($a:expr, $b:expr) => {
+        $crate::assert_approx_eq!($a, $b, $crate::DEFAULT_EPSILON, $crate::DEFAULT_MAX_RELATIVE)
+    };
+    ($a:expr, $b:expr, $epsilon:expr) => {
+        $crate::assert_approx_eq!($a, $b, $epsilon, $crate::DEFAULT_MAX_RELATIVE)
+    };
+    ($a:expr, $b:expr, $epsilon:expr, $max_relative:expr) => {
+        match (&$a, &$b) {
+            (a, b) => {
+                if !$crate::ApproxEq::relative_eq(a, b, $epsilon, $max_relative) {
+                    panic!("assertion `left ≈ right` failed\n  left: {:?}\n right: {:?}", a, b);
+                }
+            }
+        }
+    };
+}
+
+/// Asserts that two values differ by at most `epsilon` in every component
+#[macro_export]
+macro_rules! assert_abs_diff_eq {
+    ($a:expr, $b:expr) => {
+        $crate::assert_abs_diff_eq!($a, $b, $crate::DEFAULT_EPSILON)
+    };
+    ($a:expr, $b:expr, $epsilon:expr) => {
+        match (&$a, &$b) {
+            (a, b) => {
+                if !$crate::ApproxEq::abs_diff_eq(a, b, $epsilon) {
+                    panic!("assertion `|left - right| <= {:?}` failed\n  left: {:?}\n right: {:?}", $epsilon, a, b);
+                }
+            }
+        }
+    };
+}
+
+/// Asserts that two values are at most `max_ulps` representable floats apart in every component
+#[macro_export]
+macro_rules! assert_ulps_eq {
+    ($a:expr, $b:expr) => {
+        $crate::assert_ulps_eq!($a, $b, $crate::DEFAULT_MAX_ULPS)
+    };
+    ($a:expr, $b:expr, $max_ulps:expr) => {
+        match (&$a, &$b) {
+            (a, b) => {
+                if !$crate::ApproxEq::ulps_eq(a, b, 0.0, $max_ulps) {
+                    panic!("assertion `left ≈ right` within {} ulps failed\n  left: {:?}\n right: {:?}", $max_ulps, a, b);
+                }
+            }
+        }
+    };
+}
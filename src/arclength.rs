@@ -0,0 +1,151 @@
+// ##################################################
+// #            GAUSS-LEGENDRE QUADRATURE           #
+// ##################################################
+
+/// Absolute error in arc length used when the caller does not pass a tolerance
+pub const DEFAULT_ARC_LENGTH_TOLERANCE: f32 = 1e-4;
+
+static GaussNodes: [f64; 5] = [
+    0.0, -0.538_469_310_105_683_1, 0.538_469_310_105_683_1, -0.906_179_845_938_664, 0.906_179_845_938_664
+];
+
+static GaussWeights: [f64; 5] = [
+    0.568_888_888_888_888_9, 0.478_628_670_499_366_5, 0.478_628_670_499_366_5, 0.236_926_885_056_189_1, 0.236_926_885_056_189_1
+];
+
+/// Five point Gauss-Legendre quadrature of `f` over `[a, b]`
+fn gaussLegendre(f: &dyn Fn(f32) -> f32, a: f64, b: f64) -> f64 {
+    let half = 0.5 * (b - a);
+    let center = 0.5 * (a + b);
+    GaussNodes.iter().zip(GaussWeights.iter())
+        .map(|(x, w)| w * f((center + half * x) as f32) as f64)
+        .sum::<f64>() * half
+}
+
+fn adaptiveGaussLegendre(f: &dyn Fn(f32) -> f32, a: f64, b: f64, whole: f64, tolerance: f64, depth: u32) -> f64 {
+    let mid = 0.5 * (a + b);
+    let left = gaussLegendre(f, a, mid);
+    let right = gaussLegendre(f, mid, b);
+    if depth == 0 || (left + right - whole).abs() <= tolerance {
+        return left + right;
+    }
+    adaptiveGaussLegendre(f, a, mid, left, 0.5 * tolerance, depth - 1)
+        + adaptiveGaussLegendre(f, mid, b, right, 0.5 * tolerance, depth - 1)
+}
+
+/// Integral of `f` over `[a, b]`, subdividing adaptively until the estimated error is below `tolerance`
+pub(crate) fn integrate(f: &dyn Fn(f32) -> f32, a: f32, b: f32, tolerance: f32) -> f32 {
+    let whole = gaussLegendre(f, a as f64, b as f64);
+    adaptiveGaussLegendre(f, a as f64, b as f64, whole, tolerance as f64, 16) as f32
+}
+
+/// Parameter `t` in `[0, 1]` at which the arc length measured from `t = 0` reaches `length`.
+/// Uses Newton's method on the arc length function, guarded by bisection.
+pub(crate) fn invertArcLength(speed: &dyn Fn(f32) -> f32, length: f32, tolerance: f32) -> f32 {
+    let total = integrate(speed, 0.0, 1.0, tolerance);
+    if length <= 0.0 {
+        return 0.0;
+    }
+    if length >= total {
+        return 1.0;
+    }
+
+    let (mut lo, mut hi) = (0.0_f32, 1.0_f32);
+    let mut t = length / total;
+    for _ in 0..32 {
+        let error = integrate(speed, 0.0, t, tolerance) - length;
+        if error.abs() <= tolerance {
+            break;
+        }
+        if error > 0.0 { hi = t } else { lo = t }
+
+        let v = speed(t);
+        let newton = t - error / v;
+        t = if v > 0.0 && newton > lo && newton < hi { newton } else { 0.5 * (lo + hi) };
+    }
+    t
+}
+
+// ##################################################
+// #               ARC LENGTH TABLE                 #
+// ##################################################
+
+/// Precomputed mapping between curve parameter and arc length for fast repeated lookups.
+/// Samples are placed adaptively so that interpolating between them is off by at most the
+/// tolerance given on construction.
+/// The interval is always split a few times first, so symmetric curves cannot fool the midpoint test.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ArcLengthTable {
+    params: Vec<f32>,
+    lengths: Vec<f32>
+}
+
+impl ArcLengthTable {
+    /// Builds the table from the speed `|dC/dt|` of a curve over `t ∈ [0, 1]`
+    pub fn from_speed(speed: &dyn Fn(f32) -> f32, tolerance: f32) -> Self {
+        let mut params = vec![0.0];
+        let mut lengths = vec![0.0];
+        let quadratureTolerance = 0.1 * tolerance;
+        Self::refine(speed, 0.0, 1.0, 0.0, integrate(speed, 0.0, 1.0, quadratureTolerance), tolerance, quadratureTolerance, 0, &mut params, &mut lengths);
+        Self { params, lengths }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn refine(speed: &dyn Fn(f32) -> f32, t0: f32, t1: f32, s0: f32, segment: f32, tolerance: f32, quadratureTolerance: f32, depth: u32, params: &mut Vec<f32>, lengths: &mut Vec<f32>) {
+        const MinDepth: u32 = 4;
+        const MaxDepth: u32 = 16;
+
+        let tMid = 0.5 * (t0 + t1);
+        let left = integrate(speed, t0, tMid, quadratureTolerance);
+        let converged = depth >= MinDepth && (left - 0.5 * segment).abs() <= tolerance;
+        if converged || depth == MaxDepth {
+            params.push(t1);
+            lengths.push(s0 + segment);
+            return;
+        }
+        Self::refine(speed, t0, tMid, s0, left, tolerance, quadratureTolerance, depth + 1, params, lengths);
+        Self::refine(speed, tMid, t1, s0 + left, segment - left, tolerance, quadratureTolerance, depth + 1, params, lengths);
+    }
+
+    /// Total arc length of the curve
+    pub fn length(&self) -> f32 {
+        self.lengths[self.lengths.len() - 1]
+    }
+
+    /// Number of samples in the table
+    pub fn len(&self) -> usize {
+        self.params.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.params.is_empty()
+    }
+
+    /// Arc length from the start of the curve to the parameter `t`
+    pub fn length_at_t(&self, t: f32) -> f32 {
+        Self::interpolate(&self.params, &self.lengths, t)
+    }
+
+    /// Parameter at which the arc length from the start of the curve reaches `length`
+    pub fn t_at_length(&self, length: f32) -> f32 {
+        Self::interpolate(&self.lengths, &self.params, length)
+    }
+
+    fn interpolate(keys: &[f32], values: &[f32], key: f32) -> f32 {
+        let last = keys.len() - 1;
+        if key <= keys[0] {
+            return values[0];
+        }
+        if key >= keys[last] {
+            return values[last];
+        }
+
+        let i = keys.partition_point(|&k| k <= key).min(last);
+        let span = keys[i] - keys[i - 1];
+        if span <= 0.0 {
+            return values[i];
+        }
+        let alpha = (key - keys[i - 1]) / span;
+        values[i - 1] + (values[i] - values[i - 1]) * alpha
+    }
+}
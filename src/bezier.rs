@@ -1,23 +1,742 @@
-use crate::vector::{Vec2, Vec3};
+use crate::point::{Point2, Point3};
+use crate::mesh::Mesh;
+use crate::bounds::{Aabb2, Aabb3};
+use crate::polynomial::{bernsteinDot, bernsteinRoots};
+use crate::vector::{Vec2, Vec3, InnerSpace, cross};
+use crate::utilities::gaussianElimination;
+use crate::arclength::{ArcLengthTable, DEFAULT_ARC_LENGTH_TOLERANCE, integrate, invertArcLength};
 
+// ##################################################
+// #                  DE CASTELJAU                  #
+// ##################################################
+
+/// Evaluates the curve with the given control points by repeated linear interpolation
+pub(crate) fn deCasteljau<T: InnerSpace>(points: &[T], t: f32) -> T {
+    let mut scratch = points.to_vec();
+    for level in (1..scratch.len()).rev() {
+        for i in 0..level {
+            scratch[i] = scratch[i] * (1.0 - t) + scratch[i + 1] * t;
+        }
+    }
+    scratch[0]
+}
+
+/// Control points of the derivative curve, which is one degree lower
+pub(crate) fn hodograph<T: InnerSpace>(points: &[T]) -> Vec<T> {
+    let degree = points.len() - 1;
+    if degree == 0 {
+        return vec![points[0] * 0.0];
+    }
+    points.windows(2).map(|w| (w[1] - w[0]) * degree as f32).collect()
+}
+
+/// Splits the control polygon at `t` using the de Casteljau pyramid.
+/// The left edge of the pyramid controls `[0, t]`, the right edge controls `[t, 1]`.
+fn splitPoints<T: InnerSpace>(points: &[T], t: f32) -> (Vec<T>, Vec<T>) {
+    let mut scratch = points.to_vec();
+    let mut left = Vec::with_capacity(points.len());
+    let mut right = Vec::with_capacity(points.len());
+    left.push(scratch[0]);
+    right.push(scratch[scratch.len() - 1]);
+
+    for level in (1..scratch.len()).rev() {
+        for i in 0..level {
+            scratch[i] = scratch[i] * (1.0 - t) + scratch[i + 1] * t;
+        }
+        left.push(scratch[0]);
+        right.push(scratch[level - 1]);
+    }
+
+    right.reverse();
+    (left, right)
+}
+
+/// Control points of the part of the curve between `t0` and `t1`, reversed if `t0 > t1`.
+/// Parameters outside `[0, 1]` extend the curve polynomially.
+fn subsegmentPoints<T: InnerSpace>(points: &[T], t0: f32, t1: f32) -> Vec<T> {
+    if t0 > t1 {
+        let mut reversed = subsegmentPoints(points, t1, t0);
+        reversed.reverse();
+        return reversed;
+    }
+
+    if t1 > 0.0 {
+        // Cut off the end first, then find t0 on the remaining [0, t1]
+        let (head, _) = splitPoints(points, t1);
+        let (_, segment) = splitPoints(&head, t0 / t1);
+        segment
+    } else {
+        // Here t0 <= t1 <= 0, so cut off the start first and find t1 on the remaining [t0, 1]
+        let (_, tail) = splitPoints(points, t0);
+        let (segment, _) = splitPoints(&tail, (t1 - t0) / (1.0 - t0));
+        segment
+    }
+}
+
+/// Control points of the same curve expressed with one more degree
+fn elevatePoints<T: InnerSpace>(points: &[T]) -> Vec<T> {
+    let n = points.len();
+    let mut elevated = Vec::with_capacity(n + 1);
+    elevated.push(points[0]);
+    for i in 1..n {
+        let alpha = i as f32 / n as f32;
+        elevated.push(points[i - 1] * alpha + points[i] * (1.0 - alpha));
+    }
+    elevated.push(points[n - 1]);
+    elevated
+}
+
+/// Control points of the curve one degree lower whose elevation is closest to the original
+/// control polygon in the least-squares sense. The end points are kept fixed.
+fn reducePoints<T: InnerSpace>(points: &[T]) -> Option<Vec<T>> {
+    let n = points.len() - 1;
+    if n < 2 {
+        return None;
+    }
+    let m = n - 1;
+
+    // elevation[i][j] is the weight of the reduced point j in the elevated point i
+    let mut elevation = vec![vec![0.0; m + 1]; n + 1];
+    for (i, row) in elevation.iter_mut().enumerate() {
+        let alpha = i as f32 / n as f32;
+        if i > 0 {
+            row[i - 1] = alpha;
+        }
+        if i <= m {
+            row[i] = 1.0 - alpha;
+        }
+    }
+
+    let first = points[0];
+    let last = points[n];
+    let unknowns = m - 1;
+    let mut normal = vec![vec![0.0; unknowns]; unknowns];
+    let mut rhs = vec![first * 0.0; unknowns];
+    for (i, row) in elevation.iter().enumerate() {
+        let residual = points[i] - first * row[0] - last * row[m];
+        for u in 0..unknowns {
+            rhs[u] = rhs[u] + residual * row[u + 1];
+            for v in 0..unknowns {
+                normal[u][v] += row[u + 1] * row[v + 1];
+            }
+        }
+    }
+
+    let mut reduced = vec![first];
+    reduced.extend(gaussianElimination(normal, rhs)?);
+    reduced.push(last);
+    Some(reduced)
+}
+
+/// Distance from `p` to the line segment between `a` and `b`
+fn segmentDistance<T: InnerSpace>(p: T, a: T, b: T) -> f32 {
+    let chord = b - a;
+    let lengthSq = chord.length_squared();
+    if lengthSq == 0.0 {
+        return (p - a).length_squared().sqrt();
+    }
+    let t = ((p - a).dot(&chord) / lengthSq).clamp(0.0, 1.0);
+    let offset = p - (a + chord * t);
+    offset.length_squared().sqrt()
+}
+
+/// Appends the end points of line segments approximating the curve to `out`, excluding its start.
+/// A piece counts as flat once every control point lies within `tolerance` of its chord;
+/// the convex hull property then keeps the whole piece within `tolerance` of that chord.
+fn flattenPoints<T: InnerSpace>(points: &[T], tolerance: f32, depth: u32, out: &mut Vec<T>) {
+    let first = points[0];
+    let last = points[points.len() - 1];
+    let flat = points[1..points.len() - 1].iter().all(|p| segmentDistance(*p, first, last) <= tolerance);
+    if flat || depth >= 16 {
+        out.push(last);
+        return;
+    }
+
+    let (left, right) = splitPoints(points, 0.5);
+    flattenPoints(&left, tolerance, depth + 1, out);
+    flattenPoints(&right, tolerance, depth + 1, out);
+}
+
+// ##################################################
+// #                BEZIER CURVE 2D                 #
+// ##################################################
+
+/// Bézier curve of arbitrary degree in R²
+#[derive(Debug, Clone, PartialEq)]
 pub struct Bezier2 {
     points: Vec<Vec2>,
-    degree: i8
+    degree: usize
 }
 
 impl Bezier2 {
+    /// Curve through the given control points. Panics if `points` is empty.
     pub fn new(points: Vec<Vec2>) -> Self {
-        Self { points: points.clone(), degree: points.len() as i8 }
+        assert!(!points.is_empty(), "A Bézier curve needs at least one control point!");
+        let degree = points.len() - 1;
+        Self { points, degree }
+    }
+
+    pub fn points(&self) -> &[Vec2] {
+        &self.points
+    }
+
+    pub fn degree(&self) -> usize {
+        self.degree
+    }
+
+    pub fn eval(&self, t: f32) -> Point2 {
+        Point2::fromVec(deCasteljau(&self.points, t))
+    }
+
+    /// Derivative of the curve with respect to `t`
+    pub fn derivative(&self) -> Self {
+        Self::new(hodograph(&self.points))
+    }
+
+    /// Velocity of the curve at `t`, i.e. the unnormalized first derivative
+    pub fn velocity(&self, t: f32) -> Vec2 {
+        deCasteljau(&hodograph(&self.points), t)
+    }
+
+    /// Unit tangent at `t`, or zero where the curve is degenerate
+    pub fn tangent(&self, t: f32) -> Vec2 {
+        self.velocity(t).normalize_or_zero()
+    }
+
+    /// Unit normal at `t`, the tangent rotated counterclockwise by 90°
+    pub fn normal(&self, t: f32) -> Vec2 {
+        self.tangent(t).perp()
     }
 }
 
+// ##################################################
+// #                BEZIER CURVE 3D                 #
+// ##################################################
+
+/// Bézier curve of arbitrary degree in R³
+#[derive(Debug, Clone, PartialEq)]
 pub struct Bezier3 {
     points: Vec<Vec3>,
-    degree: i8
+    degree: usize
 }
 
 impl Bezier3 {
+    /// Curve through the given control points. Panics if `points` is empty.
     pub fn new(points: Vec<Vec3>) -> Self {
-        Self { points: points.clone(), degree: points.len() as i8 }
+        assert!(!points.is_empty(), "A Bézier curve needs at least one control point!");
+        let degree = points.len() - 1;
+        Self { points, degree }
+    }
+
+    pub fn points(&self) -> &[Vec3] {
+        &self.points
+    }
+
+    pub fn degree(&self) -> usize {
+        self.degree
+    }
+
+    pub fn eval(&self, t: f32) -> Point3 {
+        Point3::fromVec(deCasteljau(&self.points, t))
+    }
+
+    /// Derivative of the curve with respect to `t`
+    pub fn derivative(&self) -> Self {
+        Self::new(hodograph(&self.points))
+    }
+
+    /// Velocity of the curve at `t`, i.e. the unnormalized first derivative
+    pub fn velocity(&self, t: f32) -> Vec3 {
+        deCasteljau(&hodograph(&self.points), t)
+    }
+
+    /// Unit tangent at `t`, or zero where the curve is degenerate
+    pub fn tangent(&self, t: f32) -> Vec3 {
+        self.velocity(t).normalize_or_zero()
+    }
+
+    /// Unit principal normal at `t` pointing towards the center of curvature,
+    /// or zero where the curve is locally straight
+    pub fn normal(&self, t: f32) -> Vec3 {
+        let velocity = self.velocity(t);
+        let acceleration = self.derivative().velocity(t);
+        cross(cross(velocity, acceleration), velocity).normalize_or_zero()
+    }
+}
+
+// ##################################################
+// #                  SUBDIVISION                   #
+// ##################################################
+
+macro_rules! impl_bezier_subdivision {
+    ($name:ident) => {
+        impl $name {
+            /// Splits the curve at `t` into the curves over `[0, t]` and `[t, 1]`
+            pub fn split(&self, t: f32) -> (Self, Self) {
+                let (left, right) = splitPoints(&self.points, t);
+                (Self::new(left), Self::new(right))
+            }
+            /// The part of the curve between `t0` and `t1`, reparameterized to `[0, 1]`. Parameters outside `[0, 1]` extrapolate the curve.
+            /// The part of the curve between `t0` and `t1`, reparameterized to `[0, 1]`
+            pub fn subsegment(&self, t0: f32, t1: f32) -> Self {
+                Self::new(subsegmentPoints(&self.points, t0, t1))
+            }
+
+            /// The same curve with one more control point
+            pub fn elevate_degree(&self) -> Self {
+                Self::new(elevatePoints(&self.points))
+            }
+
+            /// Least-squares approximation of the curve one degree lower with the same end points.
+            /// Returns `None` for curves of degree less than two.
+            pub fn reduce_degree(&self) -> Option<Self> {
+                reducePoints(&self.points).map(Self::new)
+            }
+
+            /// The same curve traversed from end to start
+            pub fn reverse(&self) -> Self {
+                Self::new(self.points.iter().rev().copied().collect())
+            }
+        }
+    };
+}
+
+impl_bezier_subdivision!(Bezier2);
+impl_bezier_subdivision!(Bezier3);
+
+// ##################################################
+// #                  ARC LENGTH                    #
+// ##################################################
+
+macro_rules! impl_bezier_arc_length {
+    ($name:ident) => {
+        impl $name {
+            /// Length of the curve, integrated with adaptive Gauss-Legendre quadrature
+            pub fn arc_length(&self) -> f32 {
+                self.arc_length_with_tolerance(DEFAULT_ARC_LENGTH_TOLERANCE)
+            }
+
+            /// Length of the curve with an absolute error of at most `tolerance`
+            pub fn arc_length_with_tolerance(&self, tolerance: f32) -> f32 {
+                let hodograph = hodograph(&self.points);
+                integrate(&|t| deCasteljau(&hodograph, t).length(), 0.0, 1.0, tolerance)
+            }
+
+            /// Parameter at which the arc length from the start of the curve reaches `length`.
+            /// Lengths outside of `[0, arc_length()]` are clamped to the end points.
+            pub fn t_at_length(&self, length: f32) -> f32 {
+                self.t_at_length_with_tolerance(length, DEFAULT_ARC_LENGTH_TOLERANCE)
+            }
+
+            pub fn t_at_length_with_tolerance(&self, length: f32, tolerance: f32) -> f32 {
+                let hodograph = hodograph(&self.points);
+                invertArcLength(&|t| deCasteljau(&hodograph, t).length(), length, tolerance)
+            }
+
+            /// Precomputes the arc length parameterization so lookups are off by at most `tolerance` in length
+            pub fn arc_length_table(&self, tolerance: f32) -> ArcLengthTable {
+                let hodograph = hodograph(&self.points);
+                ArcLengthTable::from_speed(&|t| deCasteljau(&hodograph, t).length(), tolerance)
+            }
+        }
+    };
+}
+
+impl_bezier_arc_length!(Bezier2);
+impl_bezier_arc_length!(Bezier3);
+
+// ##################################################
+// #            EXTREMA AND CLOSEST POINT           #
+// ##################################################
+
+macro_rules! impl_bezier_queries {
+    ($name:ident, $point:ident, $vector:ident, $aabb:ident, $($field:ident),+) => {
+        impl $name {
+            /// Parameters in `(0, 1)` where some coordinate of the curve has a local extremum, sorted.
+            /// These are the sign-changing roots of the derivative's components.
+            pub fn extrema(&self) -> Vec<f32> {
+                let hodograph = hodograph(&self.points);
+                let mut params = Vec::new();
+                $(
+                    let coeffs: Vec<f32> = hodograph.iter().map(|p| p.$field).collect();
+                    params.extend(bernsteinRoots(&coeffs).into_iter().filter(|t| *t > 0.0 && *t < 1.0));
+                )+
+                params.sort_by(f32::total_cmp);
+                params.dedup_by(|a, b| (*a - *b).abs() < 1e-6);
+                params
+            }
+
+            /// Tight axis-aligned bounding box of the curve, spanned by the end points and the extrema
+            pub fn bounding_box(&self) -> $aabb {
+                let params = [0.0, 1.0].into_iter().chain(self.extrema());
+                $aabb::from_points(params.map(|t| self.eval(t))).unwrap()
+            }
+
+            /// Parameter, position and distance of the point on the curve closest to `p`.
+            /// Candidates are the end points and the roots of `(C(t) - p)·C'(t)`.
+            pub fn closest_point(&self, p: $point) -> (f32, $point, f32) {
+                let offset: Vec<$vector> = self.points.iter().map(|c| *c - $vector::fromPoint(p)).collect();
+                let candidates = bernsteinRoots(&bernsteinDot(&offset, &hodograph(&self.points)));
+
+                [0.0, 1.0].into_iter().chain(candidates)
+                    .map(|t| {
+                        let q = self.eval(t);
+                        (t, q, q.distance(&p))
+                    })
+                    .min_by(|a, b| a.2.total_cmp(&b.2))
+                    .unwrap()
+            }
+        }
+    };
+}
+
+impl_bezier_queries!(Bezier2, Point2, Vec2, Aabb2, x, y);
+impl_bezier_queries!(Bezier3, Point3, Vec3, Aabb3, x, y, z);
+
+// ##################################################
+// #                  FLATTENING                    #
+// ##################################################
+
+macro_rules! impl_bezier_flatten {
+    ($name:ident, $point:ident) => {
+        impl $name {
+            /// Points of a polyline that stays within `tolerance` of the curve, starting at `eval(0)` and ending at `eval(1)`.
+            /// The curve is subdivided recursively, so strongly curved parts get more segments than flat ones.
+            pub fn flatten(&self, tolerance: f32) -> impl Iterator<Item = $point> {
+                let mut polyline = vec![self.points[0]];
+                if self.degree > 0 {
+                    flattenPoints(&self.points, tolerance, 0, &mut polyline);
+                }
+                polyline.into_iter().map($point::fromVec)
+            }
+        }
+    };
+}
+
+impl_bezier_flatten!(Bezier2, Point2);
+impl_bezier_flatten!(Bezier3, Point3);
+
+// ##################################################
+// #                 INTERSECTIONS                  #
+// ##################################################
+
+/// Intersection of a curve with another curve, segment or ray
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Intersection2 {
+    /// Parameter on the curve the query was called on
+    pub t: f32,
+    /// Parameter on the other curve, segment or ray
+    pub other_t: f32,
+    pub point: Point2
+}
+
+/// Parameter range of the distance function `(i/n, distances[i])` whose convex hull lies inside `[dMin, dMax]`.
+/// Every pair of control points spans part of the hull, so intersecting all of them with the band is enough.
+fn clipToFatLine(distances: &[f32], dMin: f32, dMax: f32) -> Option<(f32, f32)> {
+    let n = (distances.len() - 1) as f32;
+    let mut lo = f32::INFINITY;
+    let mut hi = f32::NEG_INFINITY;
+    let mut extend = |t: f32| { lo = lo.min(t); hi = hi.max(t); };
+
+    for (i, di) in distances.iter().enumerate() {
+        let ti = i as f32 / n;
+        if *di >= dMin && *di <= dMax {
+            extend(ti);
+        }
+        for (j, dj) in distances.iter().enumerate().skip(i + 1) {
+            let tj = j as f32 / n;
+            for bound in [dMin, dMax] {
+                if (di - bound) * (dj - bound) < 0.0 {
+                    extend(ti + (tj - ti) * (bound - di) / (dj - di));
+                }
+            }
+        }
+    }
+
+    if lo > hi {
+        return None;
+    }
+    Some((lo.max(0.0), hi.min(1.0)))
+}
+
+struct ClipState<'a> {
+    a: &'a [Vec2],
+    b: &'a [Vec2],
+    tolerance: f32,
+    budget: usize,
+    hits: Vec<(f32, f32)>
+}
+
+/// Bézier clipping: alternately clips each curve against the fat line of the other
+/// and splits whichever curve has the wider parameter range when clipping stalls.
+fn bezierClip(state: &mut ClipState, aRange: (f32, f32), bRange: (f32, f32), depth: u32) {
+    if state.budget == 0 {
+        return;
+    }
+    state.budget -= 1;
+
+    let aPoints = subsegmentPoints(state.a, aRange.0, aRange.1);
+    let bPoints = subsegmentPoints(state.b, bRange.0, bRange.1);
+    let aBounds = Aabb2::from_points(aPoints.iter().map(|p| Point2::fromVec(*p))).unwrap();
+    let bBounds = Aabb2::from_points(bPoints.iter().map(|p| Point2::fromVec(*p))).unwrap();
+    if !aBounds.intersects(&bBounds) {
+        return;
+    }
+
+    let aSize = aBounds.size().max_element();
+    let bSize = bBounds.size().max_element();
+    if (aSize <= state.tolerance && bSize <= state.tolerance) || depth >= 48 {
+        state.hits.push((0.5 * (aRange.0 + aRange.1), 0.5 * (bRange.0 + bRange.1)));
+        return;
+    }
+
+    // Fat line along the chord of b
+    let first = bPoints[0];
+    let chord = bPoints[bPoints.len() - 1] - first;
+    let mut clipped = None;
+    if let Some(normal) = chord.perp().try_normalize() {
+        let bDistances = bPoints.iter().map(|p| (*p - first).dot(&normal));
+        let (dMin, dMax) = bDistances.fold((0.0_f32, 0.0_f32), |(lo, hi), d| (lo.min(d), hi.max(d)));
+        let aDistances: Vec<f32> = aPoints.iter().map(|p| (*p - first).dot(&normal)).collect();
+        match clipToFatLine(&aDistances, dMin - state.tolerance, dMax + state.tolerance) {
+            None => return,
+            Some(range) => clipped = Some(range)
+        }
+    }
+
+    let span = aRange.1 - aRange.0;
+    match clipped {
+        Some((lo, hi)) if hi - lo <= 0.8 => {
+            let newRange = (aRange.0 + span * lo, aRange.0 + span * hi);
+            swapped(state, |state| bezierClip(state, bRange, newRange, depth + 1));
+        }
+        _ => {
+            // Clipping did not help much, so there may be several intersections: split the wider curve
+            if span >= bRange.1 - bRange.0 {
+                let mid = 0.5 * (aRange.0 + aRange.1);
+                bezierClip(state, (aRange.0, mid), bRange, depth + 1);
+                bezierClip(state, (mid, aRange.1), bRange, depth + 1);
+            } else {
+                let mid = 0.5 * (bRange.0 + bRange.1);
+                bezierClip(state, aRange, (bRange.0, mid), depth + 1);
+                bezierClip(state, aRange, (mid, bRange.1), depth + 1);
+            }
+        }
+    }
+}
+
+/// Runs `f` with the roles of both curves exchanged
+fn swapped(state: &mut ClipState, f: impl FnOnce(&mut ClipState)) {
+    std::mem::swap(&mut state.a, &mut state.b);
+    let start = state.hits.len();
+    f(state);
+    std::mem::swap(&mut state.a, &mut state.b);
+    for hit in &mut state.hits[start..] {
+        *hit = (hit.1, hit.0);
+    }
+}
+
+impl Bezier2 {
+    /// All intersections with another curve, found with Bézier clipping against fat lines.
+    /// Intersections closer than `tolerance` to each other are reported once.
+    /// Overlapping curves have infinitely many intersections, of which only a sample is returned.
+    pub fn intersect(&self, other: &Bezier2, tolerance: f32) -> Vec<Intersection2> {
+        let mut state = ClipState { a: &self.points, b: &other.points, tolerance, budget: 20_000, hits: Vec::new() };
+        bezierClip(&mut state, (0.0, 1.0), (0.0, 1.0), 0);
+
+        let mut result: Vec<Intersection2> = Vec::new();
+        state.hits.sort_by(|a, b| a.0.total_cmp(&b.0));
+        for (t, otherT) in state.hits {
+            let (t, otherT) = self.refine_intersection(other, t, otherT);
+            let point = self.eval(t);
+            if result.iter().any(|r| r.point.distance(&point) <= tolerance) {
+                continue;
+            }
+            result.push(Intersection2 { t, other_t: otherT, point });
+        }
+        result
+    }
+
+    /// Polishes an intersection found by clipping with Newton's method on `self(t) - other(u) = 0`.
+    /// Tangential intersections make the system singular and are left as they are.
+    fn refine_intersection(&self, other: &Bezier2, mut t: f32, mut u: f32) -> (f32, f32) {
+        for _ in 0..4 {
+            let residual = Vec2::fromPoint(self.eval(t)) - Vec2::fromPoint(other.eval(u));
+            let dt = self.velocity(t);
+            let du = -other.velocity(u);
+            let det = dt.perp_dot(&du);
+            if det.abs() < 1e-12 {
+                break;
+            }
+            let newT = (t - residual.perp_dot(&du) / det).clamp(0.0, 1.0);
+            let newU = (u - dt.perp_dot(&residual) / det).clamp(0.0, 1.0);
+            (t, u) = (newT, newU);
+        }
+        (t, u)
+    }
+
+    /// Parameters where the curve crosses the line through `origin` with direction `direction`,
+    /// paired with the line parameter `u` such that the point is `origin + direction * u`
+    fn intersect_line(&self, origin: Point2, direction: Vec2) -> Vec<Intersection2> {
+        let normal = direction.perp();
+        let distances: Vec<f32> = self.points.iter().map(|p| (*p - Vec2::fromPoint(origin)).dot(&normal)).collect();
+        let lengthSq = direction.length_squared();
+
+        bernsteinRoots(&distances).into_iter()
+            .map(|t| {
+                let point = self.eval(t);
+                Intersection2 { t, other_t: (point - origin).dot(&direction) / lengthSq, point }
+            })
+            .collect()
+    }
+
+    /// Intersections with the line segment from `a` to `b`, where `other_t` is the parameter on the segment
+    pub fn intersect_line_segment(&self, a: Point2, b: Point2) -> Vec<Intersection2> {
+        let tolerance = 1e-5;
+        self.intersect_line(a, b - a).into_iter()
+            .filter(|hit| hit.other_t >= -tolerance && hit.other_t <= 1.0 + tolerance)
+            .map(|hit| Intersection2 { other_t: hit.other_t.clamp(0.0, 1.0), ..hit })
+            .collect()
+    }
+
+    /// Intersections with the ray `origin + direction * u` for `u >= 0`, where `other_t` is `u`
+    pub fn intersect_ray(&self, origin: Point2, direction: Vec2) -> Vec<Intersection2> {
+        self.intersect_line(origin, direction).into_iter()
+            .filter(|hit| hit.other_t >= 0.0)
+            .collect()
+    }
+}
+
+// ##################################################
+// #              BEZIER SURFACE PATCH              #
+// ##################################################
+
+/// Tensor-product Bézier surface over a grid of control points in R³.
+/// Rows of the grid follow the `u` direction and columns the `v` direction.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BezierPatch {
+    points: Vec<Vec3>,
+    rows: usize,
+    cols: usize
+}
+
+impl BezierPatch {
+    /// Patch over the control grid `grid[i][j]`, where `i` runs along `u` and `j` along `v`.
+    /// Panics if the grid is empty or its rows differ in length.
+    pub fn new(grid: Vec<Vec<Vec3>>) -> Self {
+        let rows = grid.len();
+        let cols = grid.first().map_or(0, |row| row.len());
+        assert!(rows > 0 && cols > 0, "A Bézier patch needs at least one control point!");
+        assert!(grid.iter().all(|row| row.len() == cols), "All rows of a Bézier patch need the same number of control points!");
+        Self { points: grid.into_iter().flatten().collect(), rows, cols }
+    }
+
+    fn fromFlat(points: Vec<Vec3>, rows: usize, cols: usize) -> Self {
+        Self { points, rows, cols }
+    }
+
+    pub fn degree_u(&self) -> usize {
+        self.rows - 1
+    }
+
+    pub fn degree_v(&self) -> usize {
+        self.cols - 1
+    }
+
+    pub fn control_point(&self, i: usize, j: usize) -> Vec3 {
+        self.points[i * self.cols + j]
+    }
+
+    fn row(&self, i: usize) -> &[Vec3] {
+        &self.points[i * self.cols..(i + 1) * self.cols]
+    }
+
+    fn column(&self, j: usize) -> Vec<Vec3> {
+        (0..self.rows).map(|i| self.control_point(i, j)).collect()
+    }
+
+    /// Collapses every row at `v`, leaving the control points of the curve running along `u`
+    fn curveAlongU(&self, v: f32) -> Vec<Vec3> {
+        (0..self.rows).map(|i| deCasteljau(self.row(i), v)).collect()
+    }
+
+    /// Collapses every column at `u`, leaving the control points of the curve running along `v`
+    fn curveAlongV(&self, u: f32) -> Vec<Vec3> {
+        (0..self.cols).map(|j| deCasteljau(&self.column(j), u)).collect()
+    }
+
+    pub fn eval(&self, u: f32, v: f32) -> Point3 {
+        Point3::fromVec(deCasteljau(&self.curveAlongU(v), u))
+    }
+
+    /// Partial derivative with respect to `u`
+    pub fn derivative_u(&self, u: f32, v: f32) -> Vec3 {
+        deCasteljau(&hodograph(&self.curveAlongU(v)), u)
+    }
+
+    /// Partial derivative with respect to `v`
+    pub fn derivative_v(&self, u: f32, v: f32) -> Vec3 {
+        deCasteljau(&hodograph(&self.curveAlongV(u)), v)
+    }
+
+    /// Unit surface normal `∂u × ∂v`.
+    /// Where the patch is degenerate, e.g. at a corner collapsed into a point, the normal is taken
+    /// slightly inside the patch instead.
+    pub fn normal(&self, u: f32, v: f32) -> Vec3 {
+        let normalAt = |u: f32, v: f32| cross(self.derivative_u(u, v), self.derivative_v(u, v)).try_normalize();
+        normalAt(u, v)
+            .or_else(|| normalAt(u + (0.5 - u) * 1e-3, v + (0.5 - v) * 1e-3))
+            .unwrap_or(Vec3::zero())
+    }
+
+    /// Splits the patch at `u` into the patches over `[0, u]` and `[u, 1]`
+    pub fn split_u(&self, u: f32) -> (Self, Self) {
+        let mut low = vec![Vec3::zero(); self.points.len()];
+        let mut high = low.clone();
+        for j in 0..self.cols {
+            let (left, right) = splitPoints(&self.column(j), u);
+            for i in 0..self.rows {
+                low[i * self.cols + j] = left[i];
+                high[i * self.cols + j] = right[i];
+            }
+        }
+        (Self::fromFlat(low, self.rows, self.cols), Self::fromFlat(high, self.rows, self.cols))
+    }
+
+    /// Splits the patch at `v` into the patches over `[0, v]` and `[v, 1]`
+    pub fn split_v(&self, v: f32) -> (Self, Self) {
+        let mut low = Vec::with_capacity(self.points.len());
+        let mut high = Vec::with_capacity(self.points.len());
+        for i in 0..self.rows {
+            let (left, right) = splitPoints(self.row(i), v);
+            low.extend(left);
+            high.extend(right);
+        }
+        (Self::fromFlat(low, self.rows, self.cols), Self::fromFlat(high, self.rows, self.cols))
+    }
+
+    /// Splits the patch at `u = v = 0.5` into the quadrants `[(low u, low v), (low u, high v), (high u, low v), (high u, high v)]`
+    pub fn subdivide(&self) -> [Self; 4] {
+        let (low, high) = self.split_u(0.5);
+        let (a, b) = low.split_v(0.5);
+        let (c, d) = high.split_v(0.5);
+        [a, b, c, d]
+    }
+
+    /// Samples the patch on a regular `(u_segments + 1) × (v_segments + 1)` grid of vertices
+    /// with positions, normals and `(u, v)` texture coordinates
+    pub fn tessellate(&self, u_segments: usize, v_segments: usize) -> Mesh {
+        let uSegments = u_segments.max(1);
+        let vSegments = v_segments.max(1);
+        let mut mesh = Mesh::new();
+        for i in 0..=uSegments {
+            let u = i as f32 / uSegments as f32;
+            for j in 0..=vSegments {
+                let v = j as f32 / vSegments as f32;
+                mesh.push_vertex(Vec3::fromPoint(self.eval(u, v)), self.normal(u, v), Vec2::new(u, v));
+            }
+        }
+        mesh.push_grid_indices(0, uSegments + 1, vSegments + 1);
+        mesh
     }
 }
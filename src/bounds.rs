@@ -0,0 +1,72 @@
+use crate::point::{Point2, Point3};
+use crate::vector::{Vec2, Vec3};
+
+macro_rules! impl_aabb {
+    ($name:ident, $point:ident, $vector:ident, $($field:ident),+) => {
+        impl $name {
+            pub const fn new(min: $point, max: $point) -> Self {
+                $name { min, max }
+            }
+
+            /// Smallest box containing all points, or `None` if there are none
+            pub fn from_points<I: IntoIterator<Item = $point>>(points: I) -> Option<Self> {
+                let mut iter = points.into_iter();
+                let first = iter.next()?;
+                let mut bounds = $name { min: first, max: first };
+                for p in iter {
+                    bounds.include(p);
+                }
+                Some(bounds)
+            }
+
+            /// Grows the box so it contains `p`
+            pub fn include(&mut self, p: $point) {
+                $(
+                    self.min.$field = self.min.$field.min(p.$field);
+                    self.max.$field = self.max.$field.max(p.$field);
+                )+
+            }
+
+            /// Smallest box containing both boxes
+            pub fn union(&self, other: &Self) -> Self {
+                $name {
+                    min: $point { $($field: self.min.$field.min(other.min.$field)),+ },
+                    max: $point { $($field: self.max.$field.max(other.max.$field)),+ }
+                }
+            }
+
+            pub fn contains(&self, p: $point) -> bool {
+                true $(&& self.min.$field <= p.$field && p.$field <= self.max.$field)+
+            }
+
+            pub fn intersects(&self, other: &Self) -> bool {
+                true $(&& self.min.$field <= other.max.$field && other.min.$field <= self.max.$field)+
+            }
+
+            pub fn size(&self) -> $vector {
+                self.max - self.min
+            }
+
+            pub fn center(&self) -> $point {
+                self.min.midpoint(&self.max)
+            }
+        }
+    };
+}
+
+/// Axis-aligned bounding box in R²
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Aabb2 {
+    pub min: Point2,
+    pub max: Point2
+}
+
+/// Axis-aligned bounding box in R³
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Aabb3 {
+    pub min: Point3,
+    pub max: Point3
+}
+
+impl_aabb!(Aabb2, Point2, Vec2, x, y);
+impl_aabb!(Aabb3, Point3, Vec3, x, y, z);
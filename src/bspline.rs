@@ -0,0 +1,186 @@
+use crate::vector::{Vec2, Vec3, InnerSpace};
+use crate::bezier::{Bezier2, Bezier3};
+
+// ##################################################
+// #                    B-SPLINES                   #
+// ##################################################
+
+/// B-spline curve of arbitrary degree over a non-decreasing knot vector.
+/// The curve is defined over `domain()`, i.e. between the knots `knots[degree]` and `knots[points.len()]`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BSpline<T> {
+    degree: usize,
+    knots: Vec<f32>,
+    points: Vec<T>
+}
+
+impl<T: InnerSpace> BSpline<T> {
+    /// Spline with an explicit knot vector, which needs `points.len() + degree + 1` non-decreasing knots.
+    /// Panics if the points or knots do not fit the degree.
+    pub fn new(degree: usize, knots: Vec<f32>, points: Vec<T>) -> Self {
+        assert!(points.len() > degree, "A B-spline of degree {} needs at least {} control points!", degree, degree + 1);
+        assert_eq!(knots.len(), points.len() + degree + 1, "A B-spline needs exactly points + degree + 1 knots!");
+        assert!(knots.windows(2).all(|w| w[0] <= w[1]), "The knot vector of a B-spline must be non-decreasing!");
+        assert!(knots[degree] < knots[points.len()], "The domain of a B-spline must not be empty!");
+        Self { degree, knots, points }
+    }
+
+    /// Spline that starts at the first and ends at the last control point,
+    /// with uniformly spaced interior knots and the domain `[0, 1]`
+    pub fn clamped(degree: usize, points: Vec<T>) -> Self {
+        assert!(points.len() > degree, "A B-spline of degree {} needs at least {} control points!", degree, degree + 1);
+        let spans = points.len() - degree;
+        let mut knots = vec![0.0; degree + 1];
+        knots.extend((1..spans).map(|i| i as f32 / spans as f32));
+        knots.extend(vec![1.0; degree + 1]);
+        Self::new(degree, knots, points)
+    }
+
+    /// Spline with uniformly spaced knots, which does not reach the first and last control points.
+    /// The domain is `[0, 1]`.
+    pub fn unclamped(degree: usize, points: Vec<T>) -> Self {
+        assert!(points.len() > degree, "A B-spline of degree {} needs at least {} control points!", degree, degree + 1);
+        let spans = (points.len() - degree) as f32;
+        let knots = (0..points.len() + degree + 1).map(|i| (i as f32 - degree as f32) / spans).collect();
+        Self::new(degree, knots, points)
+    }
+
+    /// Closed uniform spline whose end joins its start with full continuity.
+    /// The first `degree` control points are repeated at the end and the domain is `[0, 1]`.
+    pub fn periodic(degree: usize, points: Vec<T>) -> Self {
+        assert!(points.len() > degree, "A B-spline of degree {} needs at least {} control points!", degree, degree + 1);
+        let count = points.len() as f32;
+        let mut wrapped = points.clone();
+        wrapped.extend_from_slice(&points[..degree]);
+        let knots = (0..wrapped.len() + degree + 1).map(|i| (i as f32 - degree as f32) / count).collect();
+        Self::new(degree, knots, wrapped)
+    }
+
+    pub fn degree(&self) -> usize {
+        self.degree
+    }
+
+    pub fn knots(&self) -> &[f32] {
+        &self.knots
+    }
+
+    pub fn points(&self) -> &[T] {
+        &self.points
+    }
+
+    /// Parameter range over which the spline is defined
+    pub fn domain(&self) -> (f32, f32) {
+        (self.knots[self.degree], self.knots[self.points.len()])
+    }
+
+    /// Index `k` of the knot span `[knots[k], knots[k + 1])` containing `u`
+    fn span(&self, u: f32) -> usize {
+        let last = self.points.len() - 1;
+        let mut k = self.degree;
+        while k < last && self.knots[k + 1] <= u {
+            k += 1;
+        }
+        k
+    }
+
+    /// Evaluates the spline at `u` with de Boor's algorithm. `u` is clamped to the domain.
+    pub fn eval(&self, u: f32) -> T {
+        let (start, end) = self.domain();
+        let u = u.clamp(start, end);
+        let k = self.span(u);
+        let p = self.degree;
+
+        let mut d: Vec<T> = self.points[k - p..=k].to_vec();
+        for r in 1..=p {
+            for j in (r..=p).rev() {
+                let left = self.knots[j + k - p];
+                let right = self.knots[j + 1 + k - r];
+                let alpha = if right > left { (u - left) / (right - left) } else { 0.0 };
+                d[j] = d[j - 1] * (1.0 - alpha) + d[j] * alpha;
+            }
+        }
+        d[p]
+    }
+
+    /// Derivative of the spline, a B-spline of one degree lower over the same domain
+    pub fn derivative(&self) -> Self {
+        let p = self.degree;
+        let zero = self.points[0] * 0.0;
+        if p == 0 {
+            return Self { degree: 0, knots: self.knots.clone(), points: vec![zero; self.points.len()] };
+        }
+
+        let points = self.points.windows(2).enumerate().map(|(i, w)| {
+            let span = self.knots[i + p + 1] - self.knots[i + 1];
+            if span > 0.0 { (w[1] - w[0]) * (p as f32 / span) } else { zero }
+        }).collect();
+        Self { degree: p - 1, knots: self.knots[1..self.knots.len() - 1].to_vec(), points }
+    }
+
+    /// Derivative of the spline with respect to `u`
+    pub fn velocity(&self, u: f32) -> T {
+        self.derivative().eval(u)
+    }
+
+    /// Number of knots equal to `u`
+    pub fn multiplicity(&self, u: f32) -> usize {
+        self.knots.iter().filter(|k| **k == u).count()
+    }
+
+    /// Inserts the knot `u` once with Boehm's algorithm without changing the shape of the curve.
+    /// Panics if `u` lies outside of the domain.
+    pub fn insert_knot(&mut self, u: f32) {
+        let (start, end) = self.domain();
+        assert!(u >= start && u <= end, "Knot {} lies outside of the domain [{}, {}]!", u, start, end);
+        let p = self.degree;
+        let k = self.span(u);
+
+        let mut points = Vec::with_capacity(self.points.len() + 1);
+        points.extend_from_slice(&self.points[..=k - p]);
+        for i in k - p + 1..=k {
+            let left = self.knots[i];
+            let right = self.knots[i + p];
+            let alpha = if right > left { (u - left) / (right - left) } else { 0.0 };
+            points.push(self.points[i - 1] * (1.0 - alpha) + self.points[i] * alpha);
+        }
+        points.extend_from_slice(&self.points[k..]);
+
+        self.knots.insert(k + 1, u);
+        self.points = points;
+    }
+
+    /// Control points of the Bézier curves making up each non-empty knot span inside the domain, in order.
+    /// Every knot in the domain is inserted until it has multiplicity `degree`, which decouples the spans.
+    pub fn bezier_spans(&self) -> Vec<Vec<T>> {
+        let mut spline = self.clone();
+        let p = self.degree;
+        let (start, end) = self.domain();
+
+        let mut breaks: Vec<f32> = self.knots.iter().copied().filter(|k| *k >= start && *k <= end).collect();
+        breaks.dedup();
+        for u in breaks {
+            while spline.multiplicity(u) < p {
+                spline.insert_knot(u);
+            }
+        }
+
+        (p..spline.points.len())
+            .filter(|&k| spline.knots[k] < spline.knots[k + 1] && spline.knots[k] >= start && spline.knots[k + 1] <= end)
+            .map(|k| spline.points[k - p..=k].to_vec())
+            .collect()
+    }
+}
+
+impl BSpline<Vec2> {
+    /// Converts every knot span into a `Bezier2`, each parameterized over `[0, 1]`
+    pub fn to_beziers(&self) -> Vec<Bezier2> {
+        self.bezier_spans().into_iter().map(Bezier2::new).collect()
+    }
+}
+
+impl BSpline<Vec3> {
+    /// Converts every knot span into a `Bezier3`, each parameterized over `[0, 1]`
+    pub fn to_beziers(&self) -> Vec<Bezier3> {
+        self.bezier_spans().into_iter().map(Bezier3::new).collect()
+    }
+}
@@ -0,0 +1,180 @@
+use crate::bezier::{Bezier3, deCasteljau, hodograph};
+use crate::bspline::BSpline;
+use crate::point::Point3;
+use crate::utilities::{CatmullRom, HermiteSpline};
+use crate::vector::{Vec3, InnerSpace, cross};
+
+/// Below this squared length a derivative counts as vanishing
+const DegenerateEpsilon: f32 = 1e-12;
+
+// ##################################################
+// #                    FRAMES                      #
+// ##################################################
+
+/// Right-handed orthonormal frame at a point of a curve, with `binormal = tangent × normal`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Frame {
+    pub origin: Point3,
+    pub tangent: Vec3,
+    pub normal: Vec3,
+    pub binormal: Vec3
+}
+
+impl Frame {
+    /// Point at the local offset `x` along the normal and `y` along the binormal
+    pub fn to_world(&self, x: f32, y: f32) -> Point3 {
+        self.origin + self.normal * x + self.binormal * y
+    }
+}
+
+/// Curve in R³ with derivatives up to third order, which is all that is needed for its differential geometry
+pub trait Curve3 {
+    fn position(&self, t: f32) -> Point3;
+
+    /// First, second and third derivative with respect to `t`
+    fn derivatives(&self, t: f32) -> [Vec3; 3];
+
+    /// Range of `t` covering the whole curve
+    fn domain(&self) -> (f32, f32) {
+        (0.0, 1.0)
+    }
+
+    /// Curvature `|r' × r''| / |r'|³`, or zero where the curve is degenerate
+    fn curvature(&self, t: f32) -> f32 {
+        let [velocity, acceleration, _] = self.derivatives(t);
+        let speed = velocity.length();
+        if speed * speed < DegenerateEpsilon {
+            return 0.0;
+        }
+        cross(velocity, acceleration).length() / (speed * speed * speed)
+    }
+
+    /// Torsion `(r' × r'') · r''' / |r' × r''|²`, or zero where the curvature vanishes
+    fn torsion(&self, t: f32) -> f32 {
+        let [velocity, acceleration, jerk] = self.derivatives(t);
+        let binormal = cross(velocity, acceleration);
+        let lengthSquared = binormal.length_squared();
+        if lengthSquared < DegenerateEpsilon {
+            return 0.0;
+        }
+        binormal.dot(&jerk) / lengthSquared
+    }
+
+    /// Frenet-Serret frame at `t`, with the normal pointing towards the center of curvature.
+    /// Returns `None` where the curvature vanishes, e.g. on straight parts and at inflection points.
+    fn frenet_frame(&self, t: f32) -> Option<Frame> {
+        let [velocity, acceleration, _] = self.derivatives(t);
+        let tangent = velocity.try_normalize()?;
+        let binormal = cross(velocity, acceleration).try_normalize()?;
+        Some(Frame { origin: self.position(t), tangent, normal: cross(binormal, tangent), binormal })
+    }
+
+    /// Rotation-minimizing frames at the parameters `ts`, computed with the double reflection method
+    /// of Wang et al. Unlike Frenet frames they do not flip at inflection points and exist on straight parts.
+    /// The first frame is the Frenet frame if there is one, otherwise its normal is an arbitrary perpendicular.
+    fn rotation_minimizing_frames(&self, ts: &[f32]) -> Vec<Frame> {
+        let mut frames: Vec<Frame> = Vec::with_capacity(ts.len());
+        for t in ts {
+            let origin = self.position(*t);
+            let [velocity, _, _] = self.derivatives(*t);
+
+            let Some(previous) = frames.last() else {
+                let first = self.frenet_frame(*t).unwrap_or_else(|| {
+                    let tangent = velocity.try_normalize().unwrap_or(Vec3::new(0.0, 0.0, 1.0));
+                    let normal = tangent.any_orthogonal_vector();
+                    Frame { origin, tangent, normal, binormal: cross(tangent, normal) }
+                });
+                frames.push(first);
+                continue;
+            };
+
+            let tangent = velocity.try_normalize().unwrap_or(previous.tangent);
+            let reflect = |v: Vec3, axis: Vec3, axisSquared: f32| v - axis * (2.0 / axisSquared * axis.dot(&v));
+
+            // Reflect the previous frame across the bisecting plane of the two origins,
+            // then across the plane that maps the reflected tangent onto the new tangent
+            let v1 = origin - previous.origin;
+            let c1 = v1.length_squared();
+            let (reflectedNormal, reflectedTangent) = if c1 < DegenerateEpsilon {
+                (previous.normal, previous.tangent)
+            } else {
+                (reflect(previous.normal, v1, c1), reflect(previous.tangent, v1, c1))
+            };
+            let v2 = tangent - reflectedTangent;
+            let c2 = v2.length_squared();
+            let normal = if c2 < DegenerateEpsilon { reflectedNormal } else { reflect(reflectedNormal, v2, c2) };
+
+            // Remove the drift that accumulates over many steps
+            let normal = (normal - tangent * tangent.dot(&normal)).normalize_or_zero();
+            frames.push(Frame { origin, tangent, normal, binormal: cross(tangent, normal) });
+        }
+        frames
+    }
+}
+
+// ##################################################
+// #                IMPLEMENTATIONS                 #
+// ##################################################
+
+impl Curve3 for Bezier3 {
+    fn position(&self, t: f32) -> Point3 {
+        self.eval(t)
+    }
+
+    fn derivatives(&self, t: f32) -> [Vec3; 3] {
+        let first = hodograph(self.points());
+        let second = hodograph(&first);
+        let third = hodograph(&second);
+        [deCasteljau(&first, t), deCasteljau(&second, t), deCasteljau(&third, t)]
+    }
+}
+
+/// Derivatives of a cubic segment given by Bézier control points, scaled from the local to the global parameter
+fn cubicSegmentDerivatives([p0, p1, p2, p3]: [Vec3; 4], t: f32, segmentCount: usize) -> [Vec3; 3] {
+    let n = segmentCount as f32;
+    let (d0, d1, d2) = (p1 - p0, p2 - p1, p3 - p2);
+    let s = 1.0 - t;
+    let first = (d0 * (s * s) + d1 * (2.0 * s * t) + d2 * (t * t)) * 3.0;
+    let second = ((d1 - d0) * s + (d2 - d1) * t) * 6.0;
+    let third = (d2 - d1 * 2.0 + d0) * 6.0;
+    [first * n, second * (n * n), third * (n * n * n)]
+}
+
+impl Curve3 for CatmullRom<Vec3> {
+    fn position(&self, t: f32) -> Point3 {
+        Point3::fromVec(self.eval(t))
+    }
+
+    fn derivatives(&self, t: f32) -> [Vec3; 3] {
+        let (i, local) = self.locate(t);
+        cubicSegmentDerivatives(self.segment(i), local, self.segment_count())
+    }
+}
+
+impl Curve3 for HermiteSpline<Vec3> {
+    fn position(&self, t: f32) -> Point3 {
+        Point3::fromVec(self.eval(t))
+    }
+
+    fn derivatives(&self, t: f32) -> [Vec3; 3] {
+        let (i, local) = self.locate(t);
+        cubicSegmentDerivatives(self.segment(i), local, self.segment_count())
+    }
+}
+
+impl Curve3 for BSpline<Vec3> {
+    fn position(&self, t: f32) -> Point3 {
+        Point3::fromVec(self.eval(t))
+    }
+
+    fn domain(&self) -> (f32, f32) {
+        BSpline::domain(self)
+    }
+
+    fn derivatives(&self, t: f32) -> [Vec3; 3] {
+        let first = self.derivative();
+        let second = first.derivative();
+        let third = second.derivative();
+        [first.eval(t), second.eval(t), third.eval(t)]
+    }
+}
@@ -1,14 +1,43 @@
+#![allow(non_snake_case, non_upper_case_globals)]
+
 pub use utilities::*;
 pub use point::*;
 pub use vector::*;
 pub use matrix::*;
 pub use bezier::*;
+pub use mask::*;
+pub use quaternion::*;
+pub use approx::*;
+pub use arclength::*;
+pub use bounds::*;
+pub use rational::*;
+pub use mesh::*;
+pub use bspline::*;
+pub use nurbs::*;
+pub use path::*;
+pub use frame::*;
+pub use sweep::*;
+pub use stroke::*;
 
 mod utilities;
 mod point;
 mod vector;
 mod matrix;
 mod bezier;
+mod mask;
+mod quaternion;
+mod approx;
+mod arclength;
+mod bounds;
+mod polynomial;
+mod rational;
+mod mesh;
+mod bspline;
+mod nurbs;
+mod path;
+mod frame;
+mod sweep;
+mod stroke;
 
 #[cfg(test)]
 mod tests {
@@ -33,7 +62,7 @@ mod tests {
         let v1 = Vec2::new(1.0, 0.0);
         let v2 = Vec2::new(0.0, 1.0);
 
-        assert_eq!(vAngle(&v1,&v2), (std::f64::consts::PI as f32)/2.0);
+        assert_approx_eq!(vAngle(&v1,&v2), (std::f64::consts::PI as f32)/2.0);
     }
 
     #[test]
@@ -78,10 +107,1061 @@ mod tests {
         assert_eq!(dot(&v3, &v4), 32.0);
     }
 
+    #[test]
+    fn test_cross_product() {
+        let c = cross(Vec3::new(1.0, 2.0, 3.0), Vec3::new(4.0, 5.0, 6.0));
+        assert_eq!((c.x, c.y, c.z), (-3.0, 6.0, -3.0));
+
+        let z = cross(Vec3::new(1.0, 0.0, 0.0), Vec3::new(0.0, 1.0, 0.0));
+        assert_eq!((z.x, z.y, z.z), (0.0, 0.0, 1.0));
+    }
+
     #[test]
     fn test_vec4_dot_product() {
         let v5 = Vec4::new(1.0, 2.0, 3.0, 4.0);
         let v6 = Vec4::new(5.0, 6.0, 7.0, 8.0);
         assert_eq!(dot(&v5, &v6), 70.0);
     }
+
+    #[test]
+    fn test_swizzles() {
+        let mut v = Vec4::new(1.0, 2.0, 3.0, 4.0);
+        assert_eq!(v.zyx(), Vec3::new(3.0, 2.0, 1.0));
+        assert_eq!(v.xz(), Vec2::new(1.0, 3.0));
+        assert_eq!(v.wwww(), Vec4::new(4.0, 4.0, 4.0, 4.0));
+        assert_eq!(Vec2::new(5.0, 6.0).yxyx(), Vec4::new(6.0, 5.0, 6.0, 5.0));
+
+        v.set_xz(Vec2::new(7.0, 8.0));
+        assert_eq!(v, Vec4::new(7.0, 2.0, 8.0, 4.0));
+    }
+
+    #[test]
+    fn test_reflect_refract() {
+        let v = Vec3::new(1.0, -1.0, 0.0);
+        let n = Vec3::new(0.0, 1.0, 0.0);
+        assert_eq!(v.reflect(&n), Vec3::new(1.0, 1.0, 0.0));
+
+        let grazing = Vec3::new(1.0, -0.1, 0.0).normalize();
+        assert!(grazing.refract(&n, 1.5).is_none());
+        assert_eq!(Vec3::new(0.0, -1.0, 0.0).refract(&n, 1.5), Some(Vec3::new(0.0, -1.0, 0.0)));
+    }
+
+    #[test]
+    fn test_project_reject() {
+        let v = Vec2::new(3.0, 4.0);
+        let axis = Vec2::new(2.0, 0.0);
+        assert_eq!(v.project_onto(&axis), Vec2::new(3.0, 0.0));
+        assert_eq!(v.reject_from(&axis), Vec2::new(0.0, 4.0));
+    }
+
+    #[test]
+    fn test_angles() {
+        let v1 = Vec2::new(1.0, 0.0);
+        let v2 = Vec2::new(0.0, 1.0);
+        assert_eq!(v1.angle_between(&v2), std::f32::consts::FRAC_PI_2);
+        assert_eq!(v2.signed_angle(&v1), -std::f32::consts::FRAC_PI_2);
+        assert_eq!(v1.perp(), v2);
+
+        let a = Vec3::new(1.0, 0.0, 0.0);
+        let b = Vec3::new(1.0, 1e-6, 0.0);
+        assert!(a.angle_between(&b) > 0.0);
+        assert!(a.vAngle(&b) > 0.0);
+        // Rounding pushes the cosine of these past 1, which made the old acos form NaN
+        let c = Vec3::new(0.01, 0.7, 0.3);
+        assert!(vAngle(&c, &(c * 3.0)).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_slerp() {
+        let a = Vec3::new(1.0, 0.0, 0.0);
+        let b = Vec3::new(0.0, 1.0, 0.0);
+        let expected = std::f32::consts::FRAC_1_SQRT_2;
+        assert_approx_eq!(a.slerp(&b, 0.5), Vec3::new(expected, expected, 0.0));
+
+        let half = a.slerp(&-a, 0.5);
+        assert_abs_diff_eq!(half.x, 0.0);
+        assert_approx_eq!(half.length(), 1.0);
+    }
+
+    #[test]
+    fn test_safe_normalize() {
+        let zero = Vec3::zero();
+        assert_eq!(zero.try_normalize(), None);
+        assert_eq!(zero.normalize_or_zero(), zero);
+        assert_eq!(zero.normalize_or(Vec3::new(0.0, 0.0, 1.0)), Vec3::new(0.0, 0.0, 1.0));
+
+        let v = Vec2::new(3.0, 4.0);
+        assert_eq!(v.length_squared(), 25.0);
+        assert_eq!(v.try_normalize(), Some(Vec2::new(0.6, 0.8)));
+        assert!(v.normalize().is_normalized());
+        assert!(!v.is_normalized());
+        assert_eq!(v.clamp_length(0.0, 2.5), Vec2::new(1.5, 2.0));
+        assert_eq!(v.clamp_length(10.0, 20.0), Vec2::new(6.0, 8.0));
+        assert_eq!(Vec3::zero().clamp_length(1.0, 2.0), Vec3::zero());
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic]
+    fn test_normalize_zero_asserts() {
+        Vec4::zero().normalize();
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic]
+    fn test_normalize_infinite_asserts() {
+        Vec3::new(f32::INFINITY, 0.0, 0.0).normalize();
+    }
+
+    #[test]
+    fn test_orthonormal_basis() {
+        for n in [Vec3::new(0.0, 0.0, 1.0), Vec3::new(0.0, 0.0, -1.0), Vec3::new(1.0, 2.0, 3.0).normalize()] {
+            let (b1, b2) = n.any_orthonormal_pair();
+            assert!(b1.is_normalized() && b2.is_normalized());
+            assert!(dot(&n, &b1).abs() < 1e-6 && dot(&n, &b2).abs() < 1e-6 && dot(&b1, &b2).abs() < 1e-6);
+            assert!(cross(b1, b2).dist(&n) < 1e-6);
+            assert!(dot(&n, &n.any_orthogonal_vector()).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_orthonormalize() {
+        let mut basis = [Vec3::new(2.0, 0.0, 0.0), Vec3::new(1.0, 1.0, 0.0), Vec3::new(3.0, 0.0, 0.0)];
+        orthonormalize(&mut basis);
+        assert_eq!(basis, [Vec3::new(1.0, 0.0, 0.0), Vec3::new(0.0, 1.0, 0.0), Vec3::zero()]);
+
+        // Rounding leaves a tiny residual of the dependent vector, which must not be blown up to unit length
+        let mut dependent = [Vec3::new(1.0, 2.0, 3.0), Vec3::new(2.0, 4.0, 6.0), Vec3::new(0.0, 0.0, 1.0), Vec3::zero()];
+        orthonormalize(&mut dependent);
+        assert_eq!(dependent[1], Vec3::zero());
+        assert_eq!(dependent[3], Vec3::zero());
+        assert!(dependent[0].is_normalized() && dependent[2].is_normalized());
+        assert!(dot(&dependent[0], &dependent[2]).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_componentwise() {
+        let v = Vec3::new(-1.5, 0.25, 3.0);
+        assert_eq!(v.abs(), Vec3::new(1.5, 0.25, 3.0));
+        assert_eq!(v.floor(), Vec3::new(-2.0, 0.0, 3.0));
+        assert_eq!(v.fract(), Vec3::new(0.5, 0.25, 0.0));
+        assert!(Vec2::new(-1e-9, 0.0).fract().x < 1.0);
+        assert_eq!(v.clamp(Vec3::splat(0.0), Vec3::splat(1.0)), Vec3::new(0.0, 0.25, 1.0));
+        assert_eq!(v.min_element(), -1.5);
+        assert_eq!(v.max_element(), 3.0);
+    }
+
+    #[test]
+    fn test_masks() {
+        let a = Vec4::new(1.0, 2.0, 3.0, 4.0);
+        let b = Vec4::splat(2.5);
+        let mask = a.cmplt(b);
+        assert_eq!(mask, BVec4::new(true, true, false, false));
+        assert!(mask.any() && !mask.all());
+        assert!(a.cmpeq(a).all());
+        assert_eq!(Vec4::select(mask, a, b), Vec4::new(1.0, 2.0, 2.5, 2.5));
+    }
+
+    #[test]
+    fn test_approx_eq() {
+        assert!((0.1_f32 + 0.2).approx_eq(&0.3));
+        assert!(1.0_f32.ulps_eq(&(1.0 + f32::EPSILON), 0.0, 1));
+        assert!(!1.0_f32.ulps_eq(&1.001, 0.0, 4));
+        assert!(!f32::NAN.approx_eq(&f32::NAN));
+        assert!(1000.0_f32.relative_eq(&1000.01, 0.0, 1e-4));
+        assert!(!1000.0_f32.abs_diff_eq(&1000.01, 1e-3));
+
+        assert_approx_eq!(Mat2::new(0.1 + 0.2, 1.0, 0.0, 1.0), Mat2::new(0.3, 1.0, 0.0, 1.0));
+        assert_ulps_eq!(Quat::new(1.0, Vec3::new(0.1 + 0.2, 0.0, 0.0)), Quat::new(1.0, Vec3::new(0.3, 0.0, 0.0)));
+    }
+
+    #[test]
+    fn test_inverseLerp_vectors() {
+        let a = Vec3::new(0.1, 0.2, 5.0);
+        let b = Vec3::new(0.7, 1.4, 5.0);
+        assert_approx_eq!(invLerp3(a, b, lerp3(a, b, 0.3)).unwrap(), 0.3);
+        assert_eq!(invLerp3(a, b, Vec3::new(0.4, 0.0, 5.0)), None);
+        assert_approx_eq!(invLerp2(Vec2::new(0.1, 0.1), Vec2::new(0.4, 0.7), Vec2::new(0.2, 0.3)).unwrap(), 1.0 / 3.0);
+    }
+
+    #[test]
+    fn test_affine_points() {
+        let a = Point2::new(1.0, 2.0);
+        let b = Point2::new(4.0, 6.0);
+        assert_eq!(b - a, Vec2::new(3.0, 4.0));
+        assert_eq!(a + Vec2::new(3.0, 4.0), b);
+        assert_eq!(b - Vec2::new(3.0, 4.0), a);
+        assert_eq!(a.distance(&b), 5.0);
+        assert_eq!(a.midpoint(&b), Point2::new(2.5, 4.0));
+    }
+
+    #[test]
+    fn test_affine_combination() {
+        let triangle = [Point3::new(0.0, 0.0, 0.0), Point3::new(3.0, 0.0, 0.0), Point3::new(0.0, 3.0, 0.0)];
+        let centroid = Point3::affine_combination(&triangle.map(|p| (p, 1.0 / 3.0))).unwrap();
+        assert_approx_eq!(Vec3::fromPoint(centroid), Vec3::new(1.0, 1.0, 0.0));
+        assert_eq!(Point3::affine_combination(&[(triangle[1], 2.0)]), None);
+        assert_eq!(Point3::affine_combination(&[]), None);
+    }
+
+    #[test]
+    fn test_homogeneous() {
+        let p = Point3::new(1.0, 2.0, 3.0);
+        assert_eq!(p.to_homogeneous(), Vec4::new(1.0, 2.0, 3.0, 1.0));
+        assert_eq!(Point3::from_homogeneous(Vec4::new(2.0, 4.0, 6.0, 2.0)), Some(p));
+        assert_eq!(Point2::from_homogeneous(Vec3::new(1.0, 1.0, 0.0)), None);
+
+        // Only w = 0 is a point at infinity, small weights are valid
+        let small = Point3::from_homogeneous(Vec4::new(1e-6, 2e-6, 3e-6, 1e-6)).unwrap();
+        assert_approx_eq!(small, p);
+        assert_approx_eq!(Point2::from_homogeneous(Vec3::new(3e-7, -1e-7, 1e-7)).unwrap(), Point2::new(3.0, -1.0));
+    }
+
+    #[test]
+    fn test_bezier_eval() {
+        let curve = Bezier2::new(vec![Vec2::new(0.0, 0.0), Vec2::new(1.0, 2.0), Vec2::new(3.0, 2.0), Vec2::new(4.0, 0.0)]);
+        assert_eq!(curve.degree(), 3);
+        let p = |v: Vec2| Point2::new(v.x, v.y);
+        for t in [0.0, 0.25, 0.5, 0.9, 1.0] {
+            let expected = bezier2(p(curve.points()[0]), p(curve.points()[1]), p(curve.points()[2]), p(curve.points()[3]), t);
+            assert_approx_eq!(Vec2::fromPoint(curve.eval(t)), Vec2::fromPoint(expected));
+        }
+        assert_eq!(curve.derivative().degree(), 2);
+        assert_eq!(curve.velocity(0.0), Vec2::new(3.0, 6.0));
+        assert_approx_eq!(curve.tangent(0.5), Vec2::new(1.0, 0.0));
+        assert_approx_eq!(curve.normal(0.5), Vec2::new(0.0, 1.0));
+    }
+
+    #[test]
+    fn test_bezier3_normal() {
+        let curve = Bezier3::new(vec![Vec3::new(0.0, 0.0, 0.0), Vec3::new(1.0, 1.0, 0.0), Vec3::new(2.0, 0.0, 0.0)]);
+        assert_approx_eq!(curve.normal(0.5), Vec3::new(0.0, -1.0, 0.0));
+        assert_eq!(Bezier3::new(vec![Vec3::zero()]).derivative().degree(), 0);
+    }
+
+    #[test]
+    fn test_bezier_split() {
+        let curve = Bezier2::new(vec![Vec2::new(0.0, 0.0), Vec2::new(1.0, 3.0), Vec2::new(3.0, -1.0), Vec2::new(4.0, 2.0), Vec2::new(5.0, 0.0)]);
+        let (left, right) = curve.split(0.3);
+        assert_approx_eq!(Vec2::fromPoint(left.eval(0.5)), Vec2::fromPoint(curve.eval(0.15)));
+        assert_approx_eq!(Vec2::fromPoint(right.eval(0.5)), Vec2::fromPoint(curve.eval(0.65)));
+
+        let segment = curve.subsegment(0.2, 0.6);
+        assert_approx_eq!(Vec2::fromPoint(segment.eval(0.25)), Vec2::fromPoint(curve.eval(0.3)));
+        let backwards = curve.subsegment(0.6, 0.2);
+        assert_approx_eq!(Vec2::fromPoint(backwards.eval(0.0)), Vec2::fromPoint(curve.eval(0.6)));
+        assert_approx_eq!(Vec2::fromPoint(curve.reverse().eval(0.2)), Vec2::fromPoint(curve.eval(0.8)));
+
+        // Ranges before the start extend the curve instead of falling back to [0, t1]
+        let before = curve.subsegment(-0.5, -0.2);
+        for s in [0.0, 0.5, 1.0] {
+            assert_approx_eq!(Vec2::fromPoint(before.eval(s)), Vec2::fromPoint(curve.eval(-0.5 + 0.3 * s)), 1e-4);
+        }
+        assert_approx_eq!(Vec2::fromPoint(curve.subsegment(0.0, 0.0).eval(0.5)), Vec2::fromPoint(curve.eval(0.0)));
+    }
+
+    #[test]
+    fn test_bezier_degree_change() {
+        let curve = Bezier3::new(vec![Vec3::new(0.0, 0.0, 0.0), Vec3::new(1.0, 2.0, 0.0), Vec3::new(3.0, 2.0, 1.0), Vec3::new(4.0, 0.0, 1.0)]);
+        let elevated = curve.elevate_degree();
+        assert_eq!(elevated.degree(), 4);
+        for t in [0.1, 0.5, 0.8] {
+            assert_approx_eq!(Vec3::fromPoint(elevated.eval(t)), Vec3::fromPoint(curve.eval(t)));
+        }
+
+        let reduced = elevated.reduce_degree().unwrap();
+        assert_eq!(reduced.degree(), 3);
+        for (a, b) in reduced.points().iter().zip(curve.points()) {
+            assert_approx_eq!(*a, *b, 1e-4);
+        }
+        assert!(Bezier3::new(vec![Vec3::zero(), Vec3::zero()]).reduce_degree().is_none());
+    }
+
+    #[test]
+    fn test_bezier_arc_length() {
+        let line = Bezier2::new(vec![Vec2::new(0.0, 0.0), Vec2::new(1.0, 0.0), Vec2::new(4.0, 0.0)]);
+        assert_approx_eq!(line.arc_length(), 4.0);
+        // The middle control point pulls the curve, so t = 0.5 lies at x = 1.5
+        assert_approx_eq!(line.t_at_length(1.5), 0.5, 1e-4);
+
+        // Cubic approximation of a quarter circle with radius 1
+        let k = 0.552_284_8;
+        let arc = Bezier3::new(vec![Vec3::new(1.0, 0.0, 0.0), Vec3::new(1.0, k, 0.0), Vec3::new(k, 1.0, 0.0), Vec3::new(0.0, 1.0, 0.0)]);
+        assert_approx_eq!(arc.arc_length(), std::f32::consts::FRAC_PI_2, 1e-3);
+
+        let table = arc.arc_length_table(1e-4);
+        assert_approx_eq!(table.length(), arc.arc_length(), 1e-4);
+        for s in [0.1, 0.7, 1.2] {
+            assert_approx_eq!(table.t_at_length(s), arc.t_at_length(s), 1e-3);
+            assert_approx_eq!(table.length_at_t(arc.t_at_length(s)), s, 1e-3);
+        }
+    }
+
+    #[test]
+    fn test_bezier_bounds() {
+        let curve = Bezier2::new(vec![Vec2::new(0.0, 0.0), Vec2::new(1.0, 4.0), Vec2::new(3.0, -4.0), Vec2::new(4.0, 0.0)]);
+        let extrema = curve.extrema();
+        assert_eq!(extrema.len(), 2);
+        for t in &extrema {
+            assert_abs_diff_eq!(curve.velocity(*t).y, 0.0, 1e-4);
+        }
+
+        let bounds = curve.bounding_box();
+        let yMax = curve.eval(extrema[0]).y;
+        assert_approx_eq!(bounds.max.y, yMax);
+        assert_approx_eq!(bounds.min.y, -yMax);
+        assert_eq!((bounds.min.x, bounds.max.x), (0.0, 4.0));
+        for i in 0..=100 {
+            assert!(bounds.contains(curve.eval(i as f32 / 100.0)));
+        }
+    }
+
+    #[test]
+    fn test_bezier_closest_point() {
+        let arc = Bezier2::new(vec![Vec2::new(-1.0, 0.0), Vec2::new(0.0, 2.0), Vec2::new(1.0, 0.0)]);
+        let (t, q, dist) = arc.closest_point(Point2::new(0.0, 3.0));
+        assert_approx_eq!(t, 0.5);
+        assert_approx_eq!(Vec2::fromPoint(q), Vec2::new(0.0, 1.0));
+        assert_approx_eq!(dist, 2.0);
+
+        let (t, _, _) = arc.closest_point(Point2::new(-5.0, -1.0));
+        assert_eq!(t, 0.0);
+
+        let line = Bezier3::new(vec![Vec3::new(0.0, 0.0, 0.0), Vec3::new(2.0, 2.0, 2.0)]);
+        let (t, _, dist) = line.closest_point(Point3::new(1.0, 1.0, 1.0) + Vec3::new(1.0, -1.0, 0.0));
+        assert_approx_eq!(t, 0.5);
+        assert_approx_eq!(dist, std::f32::consts::SQRT_2);
+        assert_eq!(line.bounding_box(), Aabb3::new(Point3::origin(), Point3::new(2.0, 2.0, 2.0)));
+    }
+
+    #[test]
+    fn test_bezier_flatten() {
+        let line = Bezier2::new(vec![Vec2::new(0.0, 0.0), Vec2::new(1.0, 0.0), Vec2::new(2.0, 0.0)]);
+        assert_eq!(line.flatten(0.01).collect::<Vec<_>>(), vec![Point2::new(0.0, 0.0), Point2::new(2.0, 0.0)]);
+
+        let curve = Bezier2::new(vec![Vec2::new(0.0, 0.0), Vec2::new(0.0, 10.0), Vec2::new(10.0, 10.0), Vec2::new(10.0, 0.0)]);
+        let coarse = curve.flatten(0.5).count();
+        let fine: Vec<Point2> = curve.flatten(0.01).collect();
+        assert!(fine.len() > coarse);
+        assert_eq!(fine[0], Point2::new(0.0, 0.0));
+        assert_eq!(fine[fine.len() - 1], Point2::new(10.0, 0.0));
+        for i in 0..=50 {
+            let p = curve.eval(i as f32 / 50.0);
+            let nearest = fine.windows(2).map(|w| {
+                let segment = Bezier2::new(vec![Vec2::fromPoint(w[0]), Vec2::fromPoint(w[1])]);
+                segment.closest_point(p).2
+            }).fold(f32::INFINITY, f32::min);
+            assert!(nearest <= 0.01 + 1e-4);
+        }
+    }
+
+    #[test]
+    fn test_bezier_curve_intersection() {
+        let a = Bezier2::new(vec![Vec2::new(0.0, 0.0), Vec2::new(1.0, 3.0), Vec2::new(2.0, -3.0), Vec2::new(3.0, 0.0)]);
+        let b = Bezier2::new(vec![Vec2::new(0.0, 0.5), Vec2::new(3.0, -0.5)]);
+        let hits = a.intersect(&b, 1e-5);
+        assert_eq!(hits.len(), 3);
+        assert_approx_eq!(hits[1].t, 0.5, 1e-4);
+        for hit in &hits {
+            assert_abs_diff_eq!(Vec2::fromPoint(hit.point), Vec2::fromPoint(b.eval(hit.other_t)), 1e-3);
+        }
+
+        let arch = Bezier2::new(vec![Vec2::new(0.0, 0.0), Vec2::new(1.0, 2.0), Vec2::new(2.0, 0.0)]);
+        let valley = Bezier2::new(vec![Vec2::new(0.0, 1.5), Vec2::new(1.0, -0.5), Vec2::new(2.0, 1.5)]);
+        let hits = arch.intersect(&valley, 1e-5);
+        assert_eq!(hits.len(), 2);
+        for hit in hits {
+            assert_abs_diff_eq!(Vec2::fromPoint(arch.eval(hit.t)), Vec2::fromPoint(valley.eval(hit.other_t)), 1e-3);
+        }
+
+        let far = Bezier2::new(vec![Vec2::new(10.0, 10.0), Vec2::new(11.0, 12.0), Vec2::new(12.0, 10.0)]);
+        assert!(arch.intersect(&far, 1e-5).is_empty());
+    }
+
+    #[test]
+    fn test_bezier_line_intersection() {
+        let arch = Bezier2::new(vec![Vec2::new(0.0, 0.0), Vec2::new(1.0, 2.0), Vec2::new(2.0, 0.0)]);
+        let hits = arch.intersect_line_segment(Point2::new(0.0, 0.5), Point2::new(1.0, 0.5));
+        assert_eq!(hits.len(), 1);
+        assert_approx_eq!(hits[0].point.y, 0.5);
+        assert!(hits[0].other_t > 0.0 && hits[0].other_t < 1.0);
+
+        let hits = arch.intersect_ray(Point2::new(1.0, -1.0), Vec2::new(0.0, 1.0));
+        assert_eq!(hits.len(), 1);
+        assert_approx_eq!(hits[0].t, 0.5);
+        assert_approx_eq!(hits[0].other_t, 2.0);
+        assert!(arch.intersect_ray(Point2::new(1.0, -1.0), Vec2::new(0.0, -1.0)).is_empty());
+    }
+
+    #[test]
+    fn test_rational_circle() {
+        let center = Point2::new(1.0, 2.0);
+        let circle = RationalBezier2::circle(center, 3.0);
+        assert_eq!(circle.len(), 4);
+        for piece in &circle {
+            for i in 0..=10 {
+                let t = i as f32 / 10.0;
+                assert_approx_eq!(piece.eval(t).distance(&center), 3.0);
+                assert_abs_diff_eq!(dot(&piece.tangent(t), &(piece.eval(t) - center)), 0.0, 1e-4);
+            }
+        }
+        assert_approx_eq!(Vec2::fromPoint(circle[1].eval(0.0)), Vec2::new(1.0, 5.0));
+    }
+
+    #[test]
+    fn test_rational_conic() {
+        let parabola = RationalBezier2::conic(Point2::new(0.0, 0.0), Point2::new(1.0, 2.0), Point2::new(2.0, 0.0), 1.0);
+        let polynomial = Bezier2::new(parabola.points().to_vec());
+        for t in [0.2, 0.5, 0.7] {
+            assert_approx_eq!(Vec2::fromPoint(parabola.eval(t)), Vec2::fromPoint(polynomial.eval(t)));
+            assert_approx_eq!(parabola.velocity(t), polynomial.velocity(t));
+        }
+
+        let ellipse = RationalBezier3::ellipse_arc(Point3::origin(), Vec3::new(2.0, 0.0, 0.0), Vec3::new(0.0, 0.0, 1.0), 0.0, 1.0);
+        let p = ellipse[0].eval(0.37);
+        assert_approx_eq!(p.x * p.x / 4.0 + p.z * p.z, 1.0);
+        let arc = RationalBezier3::circular_arc(Point3::origin(), Vec3::new(0.0, 1.0, 0.0), 2.0, 0.0, 1.0);
+        assert_approx_eq!(Vec3::fromPoint(arc[0].eval(0.6)).length(), 2.0);
+        assert_approx_eq!(dot(&arc[0].normal(0.6), &Vec3::fromPoint(arc[0].eval(0.6))), -2.0, 1e-4);
+    }
+
+    fn wavePatch() -> BezierPatch {
+        BezierPatch::new((0..4).map(|i| {
+            (0..3).map(|j| Vec3::new(i as f32, j as f32, if (i + j) % 2 == 0 { 0.0 } else { 1.0 })).collect()
+        }).collect())
+    }
+
+    #[test]
+    fn test_bezier_patch() {
+        let flat = BezierPatch::new(vec![
+            vec![Vec3::new(0.0, 0.0, 0.0), Vec3::new(0.0, 2.0, 0.0)],
+            vec![Vec3::new(2.0, 0.0, 0.0), Vec3::new(2.0, 2.0, 0.0)]
+        ]);
+        assert_eq!(flat.eval(0.25, 0.5), Point3::new(0.5, 1.0, 0.0));
+        assert_eq!(flat.derivative_u(0.3, 0.3), Vec3::new(2.0, 0.0, 0.0));
+        assert_eq!(flat.normal(0.3, 0.7), Vec3::new(0.0, 0.0, 1.0));
+
+        let patch = wavePatch();
+        assert_eq!((patch.degree_u(), patch.degree_v()), (3, 2));
+        let u = 0.4;
+        let v = 0.7;
+        let rowCurve = Bezier3::new((0..4).map(|i| Vec3::fromPoint(Bezier3::new((0..3).map(|j| patch.control_point(i, j)).collect()).eval(v))).collect());
+        assert_approx_eq!(Vec3::fromPoint(patch.eval(u, v)), Vec3::fromPoint(rowCurve.eval(u)));
+        assert_approx_eq!(patch.derivative_u(u, v), rowCurve.velocity(u));
+    }
+
+    #[test]
+    fn test_bezier_patch_subdivision() {
+        let patch = wavePatch();
+        let [a, b, c, d] = patch.subdivide();
+        assert_approx_eq!(Vec3::fromPoint(a.eval(0.5, 0.5)), Vec3::fromPoint(patch.eval(0.25, 0.25)));
+        assert_approx_eq!(Vec3::fromPoint(b.eval(0.5, 0.5)), Vec3::fromPoint(patch.eval(0.25, 0.75)));
+        assert_approx_eq!(Vec3::fromPoint(c.eval(0.5, 0.5)), Vec3::fromPoint(patch.eval(0.75, 0.25)));
+        assert_approx_eq!(Vec3::fromPoint(d.eval(0.5, 0.5)), Vec3::fromPoint(patch.eval(0.75, 0.75)));
+
+        let mesh = patch.tessellate(4, 3);
+        assert_eq!(mesh.vertex_count(), 20);
+        assert_eq!(mesh.triangle_count(), 24);
+        assert_eq!(mesh.uvs[19], Vec2::new(1.0, 1.0));
+        assert!(mesh.normals.iter().all(|n| n.is_normalized()));
+        assert!(mesh.indices.iter().all(|i| (*i as usize) < mesh.vertex_count()));
+    }
+
+    #[test]
+    fn test_catmull_rom() {
+        let points = vec![Vec2::new(0.0, 0.0), Vec2::new(1.0, 1.0), Vec2::new(2.0, 0.0), Vec2::new(3.0, 1.0)];
+        for parameterization in [CatmullRomParameterization::Uniform, CatmullRomParameterization::Centripetal, CatmullRomParameterization::Chordal] {
+            let spline = CatmullRom::new(points.clone(), parameterization);
+            assert_eq!(spline.segment_count(), 3);
+            for (i, p) in points.iter().enumerate() {
+                assert_approx_eq!(spline.eval(i as f32 / 3.0), *p);
+            }
+            // Neighbouring segments share their tangent at the joint
+            assert_approx_eq!(spline.segment_velocity(0, 1.0), spline.segment_velocity(1, 0.0), 1e-4);
+        }
+
+        // The uniform spline has the classic tangent (P₂ - P₀) / 2
+        let uniform = CatmullRom::new(points.clone(), CatmullRomParameterization::Uniform);
+        assert_approx_eq!(uniform.segment_velocity(1, 0.0), (points[2] - points[0]) * 0.5);
+    }
+
+    #[test]
+    fn test_catmull_rom_closed() {
+        let square = vec![Vec3::new(0.0, 0.0, 0.0), Vec3::new(1.0, 0.0, 0.0), Vec3::new(1.0, 1.0, 0.0), Vec3::new(0.0, 1.0, 0.0)];
+        let loop_ = CatmullRom::closed(square.clone(), CatmullRomParameterization::Centripetal);
+        assert_eq!(loop_.segment_count(), 4);
+        assert_approx_eq!(loop_.eval(0.75), square[3]);
+        assert_approx_eq!(loop_.eval(1.0), square[0]);
+        assert_approx_eq!(loop_.tangent(0.0), loop_.tangent(1.0));
+        assert_approx_eq!(loop_.tangent(0.0), Vec3::new(1.0, -1.0, 0.0).normalize());
+    }
+
+    #[test]
+    fn test_bspline_eval() {
+        let points = vec![Vec2::new(0.0, 0.0), Vec2::new(1.0, 2.0), Vec2::new(3.0, 2.0), Vec2::new(4.0, 0.0)];
+        // A clamped spline without interior knots is a single Bézier curve
+        let spline = BSpline::clamped(3, points.clone());
+        let bezier = Bezier2::new(points.clone());
+        for u in [0.0, 0.3, 0.5, 1.0] {
+            assert_approx_eq!(spline.eval(u), Vec2::fromPoint(bezier.eval(u)));
+            assert_approx_eq!(spline.velocity(u), bezier.velocity(u), 1e-4);
+        }
+
+        // Uniform quadratic spans start at the midpoints of the control polygon
+        let uniform = BSpline::unclamped(2, points.clone());
+        assert_approx_eq!(uniform.eval(0.0), Vec2::new(0.5, 1.0));
+        assert_approx_eq!(uniform.eval(0.5), Vec2::new(2.0, 2.0));
+        assert_approx_eq!(uniform.eval(1.0), Vec2::new(3.5, 1.0));
+    }
+
+    #[test]
+    fn test_bspline_knot_insertion() {
+        let points = vec![Vec3::new(0.0, 0.0, 0.0), Vec3::new(1.0, 2.0, 0.0), Vec3::new(3.0, 2.0, 1.0), Vec3::new(4.0, 0.0, 1.0), Vec3::new(5.0, 1.0, 0.0), Vec3::new(6.0, 0.0, 2.0)];
+        let spline = BSpline::clamped(3, points);
+        let mut refined = spline.clone();
+        refined.insert_knot(0.4);
+        refined.insert_knot(0.4);
+        assert_eq!(refined.points().len(), 8);
+        assert_eq!(refined.multiplicity(0.4), 2);
+        for u in [0.1, 0.4, 0.55, 0.9] {
+            assert_approx_eq!(refined.eval(u), spline.eval(u), 1e-4);
+        }
+
+        let spans = spline.to_beziers();
+        assert_eq!(spans.len(), 3);
+        assert_approx_eq!(Vec3::fromPoint(spans[0].eval(0.5)), spline.eval(1.0 / 6.0), 1e-4);
+        assert_approx_eq!(Vec3::fromPoint(spans[2].eval(1.0)), spline.eval(1.0), 1e-4);
+    }
+
+    #[test]
+    fn test_bspline_periodic() {
+        let square = vec![Vec2::new(0.0, 0.0), Vec2::new(1.0, 0.0), Vec2::new(1.0, 1.0), Vec2::new(0.0, 1.0)];
+        let loop_ = BSpline::periodic(3, square);
+        assert_eq!(loop_.domain(), (0.0, 1.0));
+        assert_approx_eq!(loop_.eval(0.0), loop_.eval(1.0));
+        assert_approx_eq!(loop_.velocity(0.0), loop_.velocity(1.0), 1e-4);
+        assert_eq!(loop_.to_beziers().len(), 4);
+    }
+
+    fn nurbsCircle() -> (Vec<f32>, Vec<Vec3>, Vec<f32>) {
+        let h = std::f32::consts::FRAC_1_SQRT_2;
+        let knots = vec![0.0, 0.0, 0.0, 0.25, 0.25, 0.5, 0.5, 0.75, 0.75, 1.0, 1.0, 1.0];
+        let points = vec![
+            Vec3::new(1.0, 0.0, 0.0), Vec3::new(1.0, 1.0, 0.0), Vec3::new(0.0, 1.0, 0.0),
+            Vec3::new(-1.0, 1.0, 0.0), Vec3::new(-1.0, 0.0, 0.0), Vec3::new(-1.0, -1.0, 0.0),
+            Vec3::new(0.0, -1.0, 0.0), Vec3::new(1.0, -1.0, 0.0), Vec3::new(1.0, 0.0, 0.0)
+        ];
+        let weights = vec![1.0, h, 1.0, h, 1.0, h, 1.0, h, 1.0];
+        (knots, points, weights)
+    }
+
+    #[test]
+    fn test_nurbs_curve() {
+        let (knots, points, weights) = nurbsCircle();
+        let mut circle = NurbsCurve::new(2, knots, points, weights);
+        for p in circle.tessellate(32) {
+            assert_approx_eq!(Vec3::fromPoint(p).length(), 1.0);
+        }
+        for u in [0.1, 0.3, 0.8] {
+            let p = Vec3::fromPoint(circle.eval(u));
+            assert_abs_diff_eq!(dot(&circle.velocity(u), &p), 0.0, 1e-4);
+            // The acceleration of a circle has a component pointing to the center
+            assert!(dot(&circle.acceleration(u), &p) < 0.0);
+        }
+
+        let before = circle.eval(0.6);
+        circle.insert_knot(0.6);
+        assert_eq!(circle.weights().len(), 10);
+        assert_approx_eq!(Vec3::fromPoint(circle.eval(0.6)), Vec3::fromPoint(before));
+    }
+
+    #[test]
+    fn test_nurbs_surface() {
+        // Cylinder of radius 1 and height 2, extruding the circle along z
+        let (knots, points, weights) = nurbsCircle();
+        let grid = points.iter().map(|p| vec![*p, *p + Vec3::new(0.0, 0.0, 2.0)]).collect();
+        let gridWeights = weights.iter().map(|w| vec![*w, *w]).collect();
+        let mut cylinder = NurbsSurface::new(2, 1, knots, vec![0.0, 0.0, 1.0, 1.0], grid, gridWeights);
+
+        let p = cylinder.eval(0.3, 0.25);
+        assert_approx_eq!(Vec3::fromPoint(p).xy().length(), 1.0);
+        assert_approx_eq!(p.z, 0.5);
+        assert_approx_eq!(cylinder.derivative_v(0.3, 0.25), Vec3::new(0.0, 0.0, 2.0));
+        let n = cylinder.normal(0.3, 0.25);
+        assert_approx_eq!(n, Vec3::new(p.x, p.y, 0.0), 1e-4);
+
+        cylinder.insert_knot_u(0.4);
+        cylinder.insert_knot_v(0.5);
+        assert_eq!(cylinder.grid_size(), (10, 3));
+        assert_approx_eq!(Vec3::fromPoint(cylinder.eval(0.3, 0.25)), Vec3::fromPoint(p));
+
+        let mesh = cylinder.tessellate(16, 2);
+        assert_eq!(mesh.vertex_count(), 17 * 3);
+        assert!(mesh.positions.iter().all(|v| (v.xy().length() - 1.0).abs() < 1e-4));
+    }
+
+    #[test]
+    fn test_hermite_spline() {
+        let curve = HermiteSpline::new(vec![0.0, 1.0], vec![0.0, 0.0], vec![0.0, 0.0]);
+        assert_eq!(curve.eval(0.5), 0.5);
+        assert_eq!(curve.velocity(0.0), 0.0);
+
+        let points = vec![Vec2::new(0.0, 0.0), Vec2::new(1.0, 2.0), Vec2::new(3.0, 1.0)];
+        let spline = HermiteSpline::new(points.clone(), vec![Vec2::new(1.0, 0.0); 3], vec![Vec2::new(0.0, 1.0); 3]);
+        assert_eq!(spline.segment_velocity(0, 0.0), Vec2::new(0.0, 1.0));
+        assert_eq!(spline.segment_velocity(0, 1.0), Vec2::new(1.0, 0.0));
+        for (i, bezier) in spline.to_beziers().iter().enumerate() {
+            for t in [0.0, 0.4, 1.0] {
+                assert_approx_eq!(Vec2::fromPoint(bezier.eval(t)), spline.eval_segment(i, t));
+            }
+        }
+    }
+
+    #[test]
+    fn test_kochanek_bartels() {
+        let points = vec![Vec3::new(0.0, 0.0, 0.0), Vec3::new(1.0, 2.0, 0.0), Vec3::new(3.0, 1.0, 1.0), Vec3::new(4.0, 3.0, 0.0)];
+        let kb = HermiteSpline::kochanek_bartels(points.clone(), KochanekBartels::default());
+        let cr = CatmullRom::new(points.clone(), CatmullRomParameterization::Uniform);
+        for t in [0.1, 0.5, 0.9] {
+            assert_approx_eq!(kb.eval(t), cr.eval(t));
+        }
+
+        let tight = HermiteSpline::kochanek_bartels(points.clone(), KochanekBartels::new(1.0, 0.0, 0.0));
+        assert_eq!(tight.out_tangents()[1], Vec3::zero());
+
+        // With c = -1 each key is left along the chord to the next key and entered along the chord from the previous one
+        let corner = HermiteSpline::kochanek_bartels(points.clone(), KochanekBartels::new(0.0, -1.0, 0.0));
+        assert_eq!(corner.in_tangents()[1], Vec3::new(1.0, 2.0, 0.0));
+        assert_eq!(corner.out_tangents()[1], Vec3::new(2.0, -1.0, 1.0));
+        for t in [0.25, 0.5, 0.75] {
+            let into = corner.eval_segment(0, t) - points[0];
+            let outOf = corner.eval_segment(1, t) - points[1];
+            assert_approx_eq!(cross(into, points[1] - points[0]), Vec3::zero());
+            assert_approx_eq!(cross(outOf, points[2] - points[1]), Vec3::zero());
+        }
+
+        let scalar = HermiteSpline::kochanek_bartels(vec![0.0_f32, 1.0, 4.0], KochanekBartels::default());
+        assert_eq!(scalar.in_tangents()[1], 2.0);
+    }
+
+    #[test]
+    fn test_cubic_spline_natural() {
+        let data = [(0.0, 0.0), (1.0, 1.0), (2.0, 0.0), (4.0, 2.0)];
+        let spline = CubicSpline::natural(&data);
+        for (x, y) in data {
+            assert_approx_eq!(spline.eval(x), y);
+        }
+        let curvature = |x: f32| (spline.derivative(x + 1e-2) - spline.derivative(x - 1e-2)) / 2e-2;
+        assert_abs_diff_eq!(curvature(0.01), 0.0, 0.1);
+        assert_approx_eq!(spline.derivative(1.0 - 1e-4), spline.derivative(1.0 + 1e-4), 1e-2);
+        assert_eq!(spline.eval(-1.0), 0.0);
+        assert_eq!(spline.eval(5.0), 2.0);
+
+        // A clamped spline reproduces cubic data exactly
+        let cubic = |x: f32| x * x * x - 2.0 * x;
+        let samples: Vec<(f32, f32)> = [0.0, 0.5, 1.5, 2.0, 3.0].iter().map(|x| (*x, cubic(*x))).collect();
+        let clamped = CubicSpline::clamped(&samples, -2.0, 25.0);
+        assert_approx_eq!(clamped.eval(1.2), cubic(1.2), 1e-4);
+        assert_approx_eq!(clamped.derivative(2.5), 3.0 * 2.5 * 2.5 - 2.0, 1e-3);
+    }
+
+    #[test]
+    fn test_cubic_spline_monotone() {
+        let data = [(0.0, 0.0), (1.0, 0.1), (2.0, 5.0), (3.0, 5.0), (4.0, 5.2)];
+        let natural = CubicSpline::natural(&data);
+        let monotone = CubicSpline::monotone(&data);
+        assert!((0..400).any(|i| natural.eval(i as f32 / 100.0) > 5.2 || natural.eval(i as f32 / 100.0) < 0.0));
+
+        let mut previous = monotone.eval(0.0);
+        for i in 1..=400 {
+            let y = monotone.eval(i as f32 / 100.0);
+            assert!(y >= previous - 1e-6);
+            previous = y;
+        }
+        assert_eq!(monotone.eval(2.5), 5.0);
+        assert_eq!(monotone.derivative(2.5), 0.0);
+    }
+
+    #[test]
+    fn test_path_queries() {
+        let mut path = Path2::new();
+        path.move_to(Point2::new(0.0, 0.0))
+            .line_to(Point2::new(10.0, 0.0))
+            .line_to(Point2::new(10.0, 10.0))
+            .line_to(Point2::new(0.0, 10.0))
+            .close()
+            .move_to(Point2::new(20.0, 0.0))
+            .arc_to(Vec2::new(5.0, 5.0), 0.0, false, true, Point2::new(30.0, 0.0));
+
+        let subpaths = path.subpaths();
+        assert_eq!(subpaths.len(), 2);
+        assert!(subpaths[0].closed && !subpaths[1].closed);
+        assert_eq!(subpaths[0].segments.len(), 4);
+
+        let half = 5.0 * std::f32::consts::PI;
+        assert_approx_eq!(path.length(), 40.0 + half, 1e-2);
+        let bounds = path.bounding_box().unwrap();
+        assert_approx_eq!(bounds.min, Point2::new(0.0, -5.0), 1e-3);
+        assert_approx_eq!(bounds.max, Point2::new(30.0, 10.0), 1e-3);
+
+        assert_approx_eq!(path.point_at_length(15.0).unwrap(), Point2::new(10.0, 5.0), 1e-3);
+        assert_approx_eq!(path.point_at_length(40.0 + 0.5 * half).unwrap(), Point2::new(25.0, -5.0), 1e-2);
+        assert_approx_eq!(path.tangent_at_length(25.0).unwrap(), Vec2::new(-1.0, 0.0), 1e-4);
+        let samples = path.sample_by_distance(2.0);
+        assert_eq!(samples.len(), 28);
+        assert_approx_eq!(samples[3], Point2::new(6.0, 0.0), 1e-3);
+    }
+
+    #[test]
+    fn test_path_reverse_and_transform() {
+        let mut path = Path2::new();
+        path.move_to(Point2::new(0.0, 0.0))
+            .quad_to(Point2::new(1.0, 2.0), Point2::new(2.0, 0.0))
+            .cubic_to(Point2::new(3.0, -1.0), Point2::new(4.0, 1.0), Point2::new(5.0, 0.0))
+            .arc_to(Vec2::new(2.0, 1.0), 0.3, true, false, Point2::new(5.0, 3.0));
+
+        let reversed = path.reverse();
+        assert_approx_eq!(reversed.length(), path.length(), 1e-3);
+        assert_approx_eq!(reversed.point_at_length(0.0).unwrap(), Point2::new(5.0, 3.0));
+        assert_approx_eq!(reversed.point_at_length(1.5).unwrap(), path.point_at_length(path.length() - 1.5).unwrap(), 1e-3);
+        assert_eq!(reversed.reverse(), path);
+
+        let affine = Affine2::from_translation(Vec2::new(1.0, 2.0)) * Affine2::from_angle(std::f32::consts::FRAC_PI_2);
+        let moved = path.transform(&affine);
+        assert_approx_eq!(moved.length(), path.length(), 1e-3);
+        assert_approx_eq!(moved.point_at_length(2.0).unwrap(), affine.transform_point2(path.point_at_length(2.0).unwrap()), 1e-3);
+        assert_approx_eq!(path.transform(&affine.to_mat3()).point_at_length(2.0).unwrap(), moved.point_at_length(2.0).unwrap(), 1e-3);
+
+        let back = moved.transform(&affine.inverse().unwrap());
+        assert_approx_eq!(back.point_at_length(6.0).unwrap(), path.point_at_length(6.0).unwrap(), 1e-3);
+    }
+
+    #[test]
+    fn test_svg_path_parsing() {
+        let path = Path2::from_svg_path("M10,20h5v-5l-5-5z m1 1 2 2 c1 0 2 1 2 2s1 2 2 2 Q-1.5.5 0 0t1e1 0 a5 5 0 0 1 10 0").unwrap();
+        let p = Point2::new;
+        assert_eq!(path.commands(), &[
+            PathCommand::MoveTo(p(10.0, 20.0)),
+            PathCommand::LineTo(p(15.0, 20.0)),
+            PathCommand::LineTo(p(15.0, 15.0)),
+            PathCommand::LineTo(p(10.0, 10.0)),
+            PathCommand::Close,
+            PathCommand::MoveTo(p(11.0, 21.0)),
+            PathCommand::LineTo(p(13.0, 23.0)),
+            PathCommand::CubicTo(p(14.0, 23.0), p(15.0, 24.0), p(15.0, 25.0)),
+            PathCommand::CubicTo(p(15.0, 26.0), p(16.0, 27.0), p(17.0, 27.0)),
+            PathCommand::QuadTo(p(-1.5, 0.5), p(0.0, 0.0)),
+            PathCommand::QuadTo(p(1.5, -0.5), p(10.0, 0.0)),
+            PathCommand::ArcTo { radii: Vec2::new(5.0, 5.0), x_rotation: 0.0, large_arc: false, sweep: true, to: p(20.0, 0.0) }
+        ]);
+        let segments: Vec<Bezier2> = path.segments().collect();
+        assert_eq!(segments.len(), 11);
+        assert_approx_eq!(segments[9].eval(1.0), p(15.0, -5.0), 1e-4);
+
+        let reparsed = Path2::from_svg_path(&path.to_svg_path()).unwrap();
+        assert_eq!(reparsed, path);
+        assert_eq!(Path2::from_svg_path("  ").unwrap(), Path2::new());
+
+        // Arc rotations go through degrees, and a missing initial move-to becomes explicit
+        let mut rotated = Path2::new();
+        rotated.line_to(Point2::new(1.0, 0.5))
+            .arc_to(Vec2::new(3.0, 1.5), 0.7, true, false, Point2::new(-2.0, 4.0))
+            .cubic_to(Point2::new(0.1, 0.2), Point2::new(0.3, 0.4), Point2::new(0.5, 0.6));
+        let reparsed = Path2::from_svg_path(&rotated.to_svg_path()).unwrap();
+        assert_eq!(reparsed.commands()[0], PathCommand::MoveTo(Point2::origin()));
+        assert_eq!(&reparsed.commands()[1..2], &rotated.commands()[..1]);
+        assert_eq!(&reparsed.commands()[3..], &rotated.commands()[2..]);
+        match (reparsed.commands()[2], rotated.commands()[1]) {
+            (PathCommand::ArcTo { radii, x_rotation, large_arc, sweep, to }, PathCommand::ArcTo { x_rotation: original, .. }) => {
+                assert_approx_eq!(x_rotation, original);
+                assert_eq!((radii, large_arc, sweep, to), (Vec2::new(3.0, 1.5), true, false, Point2::new(-2.0, 4.0)));
+            }
+            _ => panic!("expected an arc")
+        }
+        assert_approx_eq!(reparsed.length(), rotated.length(), 1e-4);
+    }
+
+    #[test]
+    fn test_svg_path_errors() {
+        let error = |data: &str| Path2::from_svg_path(data).unwrap_err();
+        assert_eq!(error("L 0 0"), SvgPathError { offset: 0, kind: SvgPathErrorKind::ExpectedMoveTo });
+        assert_eq!(error("M 0 0 L 1 x"), SvgPathError { offset: 10, kind: SvgPathErrorKind::ExpectedNumber });
+        assert_eq!(error("M 0 0 A 1 1 0 2 0 3 3"), SvgPathError { offset: 14, kind: SvgPathErrorKind::ExpectedFlag });
+        assert_eq!(error("M 0 0 L 1"), SvgPathError { offset: 9, kind: SvgPathErrorKind::UnexpectedEnd });
+        assert_eq!(error("M 0 0 L 1 2,"), SvgPathError { offset: 12, kind: SvgPathErrorKind::UnexpectedEnd });
+        assert_eq!(error("M 0 0 é"), SvgPathError { offset: 6, kind: SvgPathErrorKind::UnexpectedCharacter('é') });
+        assert_eq!(error("M 0 0 L 1 2e").to_string(), "unexpected character 'e' at offset 11");
+    }
+
+    #[test]
+    fn test_curvature_and_torsion() {
+        // Twisted cubic (t, t², t³)
+        let cubic = Bezier3::new(vec![Vec3::zero(), Vec3::new(1.0 / 3.0, 0.0, 0.0), Vec3::new(2.0 / 3.0, 1.0 / 3.0, 0.0), Vec3::new(1.0, 1.0, 1.0)]);
+        assert_approx_eq!(cubic.curvature(0.0), 2.0);
+        assert_approx_eq!(cubic.torsion(0.0), 3.0);
+        assert_approx_eq!(cubic.curvature(0.5), 15.25_f32.sqrt() / 2.5625_f32.powf(1.5));
+        assert_approx_eq!(cubic.torsion(0.5), 12.0 / 15.25);
+
+        // Helix with radius 1 and pitch 0.5 per radian has curvature 0.8
+        let helix: Vec<Vec3> = (0..=64).map(|i| {
+            let angle = i as f32 * std::f32::consts::TAU / 32.0;
+            Vec3::new(angle.cos(), angle.sin(), 0.5 * angle)
+        }).collect();
+        let spline = CatmullRom::new(helix, CatmullRomParameterization::Centripetal);
+        for t in [0.3, 0.55, 0.7] {
+            assert_approx_eq!(spline.curvature(t), 0.8, 1e-2);
+            let frame = spline.frenet_frame(t).unwrap();
+            let axisward = Vec3::new(-frame.origin.x, -frame.origin.y, 0.0).normalize();
+            assert!(frame.normal.dot(&axisward) > 0.99);
+            assert_approx_eq!(cross(frame.tangent, frame.normal), frame.binormal);
+        }
+
+        let line = Bezier3::new(vec![Vec3::zero(), Vec3::new(1.0, 1.0, 0.0), Vec3::new(2.0, 2.0, 0.0)]);
+        assert_eq!(line.curvature(0.5), 0.0);
+        assert!(line.frenet_frame(0.5).is_none());
+    }
+
+    #[test]
+    fn test_rotation_minimizing_frames() {
+        // Planar S-curve with an inflection at t = 0.5
+        let curve = Bezier3::new(vec![Vec3::zero(), Vec3::new(1.0, 1.0, 0.0), Vec3::new(2.0, -1.0, 0.0), Vec3::new(3.0, 0.0, 0.0)]);
+        let before = curve.frenet_frame(0.2).unwrap();
+        let after = curve.frenet_frame(0.8).unwrap();
+        assert!(before.binormal.dot(&after.binormal) < -0.99);
+
+        let ts: Vec<f32> = (0..=50).map(|i| i as f32 / 50.0).collect();
+        let frames = curve.rotation_minimizing_frames(&ts);
+        assert_eq!(frames.len(), ts.len());
+        for pair in frames.windows(2) {
+            assert_approx_eq!(pair[1].binormal, frames[0].binormal, 1e-4);
+            assert!(pair[0].normal.dot(&pair[1].normal) > 0.9);
+            assert_abs_diff_eq!(pair[1].normal.dot(&pair[1].tangent), 0.0);
+        }
+
+        // On a straight line there is no Frenet frame, but rotation-minimizing frames still exist
+        let line = BSpline::clamped(1, vec![Vec3::zero(), Vec3::new(0.0, 0.0, 2.0)]);
+        let frames = line.rotation_minimizing_frames(&[0.0, 0.5, 1.0]);
+        assert_approx_eq!(frames[2].normal, frames[0].normal);
+        assert_approx_eq!(frames[1].to_world(1.0, 0.0), Point3::new(0.0, 0.0, 1.0) + frames[0].normal);
+    }
+
+    /// Checks that every triangle winds counterclockwise around the normals of its vertices
+    fn assertWindingMatchesNormals(mesh: &Mesh) {
+        for triangle in mesh.indices.chunks(3) {
+            let [a, b, c] = [0, 1, 2].map(|k| triangle[k] as usize);
+            let faceNormal = cross(mesh.positions[b] - mesh.positions[a], mesh.positions[c] - mesh.positions[a]);
+            assert!(faceNormal.dot(&(mesh.normals[a] + mesh.normals[b] + mesh.normals[c])) > 0.0);
+        }
+    }
+
+    #[test]
+    fn test_sweep_pipe() {
+        let circle: Vec<Point2> = (0..16).map(|i| {
+            let angle = i as f32 * std::f32::consts::TAU / 16.0;
+            Point2::new(0.5 * angle.cos(), 0.5 * angle.sin())
+        }).collect();
+        let path = Bezier3::new(vec![Vec3::zero(), Vec3::new(2.0, 0.0, 1.0), Vec3::new(2.0, 2.0, 2.0), Vec3::new(0.0, 4.0, 3.0)]);
+        let options = SweepOptions { path_segments: 20, caps: true, ..Default::default() };
+        let mesh = sweep(&circle, &path, &options);
+
+        assert_eq!(mesh.vertex_count(), 17 * 21 + 2 * 16);
+        assert_eq!(mesh.triangle_count(), 16 * 20 * 2 + 2 * 14);
+        assert_eq!(mesh.uvs[17 * 21 - 1], Vec2::new(1.0, 1.0));
+        assertWindingMatchesNormals(&mesh);
+
+        // Side normals point away from the path
+        for (i, position) in mesh.positions.iter().enumerate().take(17 * 21) {
+            let t = (i % 21) as f32 / 20.0;
+            let outward = (*position - Vec3::fromPoint(path.eval(t))).normalize();
+            assert!(mesh.normals[i].dot(&outward) > 0.95);
+        }
+        assert_approx_eq!(mesh.normals[17 * 21], -path.tangent(0.0), 1e-4);
+    }
+
+    #[test]
+    fn test_sweep_caps_and_profile_curves() {
+        let lShape = vec![
+            Point2::new(0.0, 0.0), Point2::new(2.0, 0.0), Point2::new(2.0, 1.0),
+            Point2::new(1.0, 1.0), Point2::new(1.0, 2.0), Point2::new(0.0, 2.0)
+        ];
+        let path = BSpline::clamped(1, vec![Vec3::zero(), Vec3::new(0.0, 0.0, 5.0)]);
+        let options = SweepOptions { path_segments: 4, caps: true, ..Default::default() };
+        let mesh = sweep(&lShape, &path, &options);
+        assertWindingMatchesNormals(&mesh);
+
+        let capStart = 7 * 5;
+        let capArea: f32 = mesh.indices[mesh.indices.len() - 12..].chunks(3).map(|triangle| {
+            let [a, b, c] = [0, 1, 2].map(|k| mesh.positions[triangle[k] as usize]);
+            cross(b - a, c - a).length() * 0.5
+        }).sum();
+        assert_approx_eq!(capArea, 3.0);
+        assert!(mesh.positions[capStart..].iter().all(|p| p.z == 0.0 || p.z == 5.0));
+
+        let line = vec![Point2::new(-1.0, 0.0), Point2::new(1.0, 0.0)];
+        let options = SweepOptions {
+            path_segments: 4,
+            closed_profile: false,
+            twist: Some(CubicSpline::natural(&[(0.0, 0.0), (1.0, std::f32::consts::FRAC_PI_2)])),
+            scale: Some(CubicSpline::natural(&[(0.0, 1.0), (1.0, 2.0)])),
+            ..Default::default()
+        };
+        let ribbon = sweep(&line, &path, &options);
+        assert_eq!(ribbon.vertex_count(), 10);
+        assert_eq!(ribbon.triangle_count(), 8);
+        let frames = path.rotation_minimizing_frames(&[0.0, 1.0]);
+        assert_approx_eq!(ribbon.positions[9], Vec3::new(0.0, 0.0, 5.0) + frames[1].binormal * 2.0, 1e-4);
+        // Halfway along, the profile is turned by 45° and scaled by 1.5
+        let halfway = (frames[0].normal + frames[0].binormal) * (-1.5 * std::f32::consts::SQRT_2);
+        assert_approx_eq!(ribbon.positions[2] - ribbon.positions[7], halfway, 1e-4);
+    }
+
+    #[test]
+    fn test_bezier_offset() {
+        let curve = Bezier2::new(vec![Vec2::new(0.0, 0.0), Vec2::new(1.0, 3.0), Vec2::new(4.0, -2.0), Vec2::new(5.0, 1.0)]);
+        for distance in [0.5, -0.5] {
+            let pieces = curve.offset(distance, 1e-3);
+            assert!(pieces.len() > 1 && pieces.iter().all(|piece| piece.degree() == 3));
+            assert_approx_eq!(pieces[0].eval(0.0), curve.eval(0.0) + curve.normal(0.0) * distance);
+            assert_approx_eq!(pieces[pieces.len() - 1].eval(1.0), curve.eval(1.0) + curve.normal(1.0) * distance, 1e-4);
+            for i in 0..=40 {
+                let t = i as f32 / 40.0;
+                let exact = curve.eval(t) + curve.normal(t) * distance;
+                let nearest = pieces.iter().map(|piece| piece.closest_point(exact).2).fold(f32::MAX, f32::min);
+                assert!(nearest <= 2e-3);
+            }
+        }
+
+        let line = Bezier2::new(vec![Vec2::new(0.0, 0.0), Vec2::new(4.0, 0.0)]);
+        assert_eq!(line.offset(1.0, 1e-3), vec![Bezier2::new(vec![Vec2::new(0.0, 1.0), Vec2::new(4.0, 1.0)])]);
+    }
+
+    #[test]
+    fn test_stroke_outline() {
+        let mut corner = Path2::new();
+        corner.move_to(Point2::new(0.0, 0.0)).line_to(Point2::new(10.0, 0.0)).line_to(Point2::new(10.0, 10.0));
+        let miterTip = PathCommand::LineTo(Point2::new(11.0, -1.0));
+
+        let style = StrokeStyle { width: 2.0, ..Default::default() };
+        let outline = corner.stroke(&style);
+        assert_eq!(outline.subpaths().len(), 1);
+        assert!(outline.subpaths()[0].closed);
+        assert!(outline.commands().contains(&miterTip));
+        let bounds = outline.bounding_box().unwrap();
+        assert_eq!((bounds.min, bounds.max), (Point2::new(0.0, -1.0), Point2::new(11.0, 10.0)));
+
+        let bevel = corner.stroke(&StrokeStyle { miter_limit: 1.2, ..style.clone() });
+        assert!(!bevel.commands().contains(&miterTip));
+        let round = corner.stroke(&StrokeStyle { join: LineJoin::Round, cap: LineCap::Round, ..style.clone() });
+        let bounds = round.bounding_box().unwrap();
+        assert_approx_eq!(bounds.min, Point2::new(-1.0, -1.0), 1e-3);
+        assert_approx_eq!(bounds.max, Point2::new(11.0, 11.0), 1e-3);
+
+        let mut square = Path2::new();
+        square.move_to(Point2::new(0.0, 0.0)).line_to(Point2::new(4.0, 0.0)).line_to(Point2::new(4.0, 4.0)).line_to(Point2::new(0.0, 4.0)).close();
+        let ring = square.stroke(&style);
+        assert_eq!(ring.subpaths().len(), 2);
+        let bounds = ring.bounding_box().unwrap();
+        assert_eq!((bounds.min, bounds.max), (Point2::new(-1.0, -1.0), Point2::new(5.0, 5.0)));
+
+        let line = Path2::from_svg_path("M 0 0 H 10").unwrap();
+        let dashed = line.stroke(&StrokeStyle { dashes: vec![2.0, 1.0], dash_offset: 0.5, ..style.clone() });
+        let dashes: Vec<Aabb2> = dashed.subpaths().iter().map(|subpath| {
+            subpath.segments.iter().map(|segment| segment.bounding_box()).reduce(|a, b| a.union(&b)).unwrap()
+        }).collect();
+        let extents: Vec<(f32, f32)> = dashes.iter().map(|bounds| (bounds.min.x, bounds.max.x)).collect();
+        assert_eq!(extents.len(), 4);
+        for (actual, expected) in extents.iter().zip([(0.0, 1.5), (2.5, 4.5), (5.5, 7.5), (8.5, 10.0)]) {
+            assert_approx_eq!(actual.0, expected.0, 1e-4);
+            assert_approx_eq!(actual.1, expected.1, 1e-4);
+        }
+        // Patterns far finer than the path fall back to a solid stroke instead of stalling or exploding
+        let long = Path2::from_svg_path("M 0 0 H 200000").unwrap();
+        let fine = StrokeStyle { dashes: vec![0.001, 0.001], ..style.clone() };
+        assert_eq!(long.stroke(&fine), long.stroke(&style));
+        assert_eq!(long.stroke_triangles(&fine).indices.len(), long.stroke_triangles(&style).indices.len());
+    }
+
+    #[test]
+    fn test_stroke_triangles() {
+        let area = |mesh: &Mesh| -> f32 {
+            mesh.indices.chunks(3).map(|triangle| {
+                let [a, b, c] = [0, 1, 2].map(|k| mesh.positions[triangle[k] as usize]);
+                let signed = 0.5 * cross(b - a, c - a).z;
+                assert!(signed > 0.0);
+                signed
+            }).sum()
+        };
+
+        let line = Path2::from_svg_path("M 0 0 L 10 0").unwrap();
+        let style = StrokeStyle { width: 2.0, ..Default::default() };
+        assert_approx_eq!(area(&line.stroke_triangles(&style)), 20.0);
+        assert_approx_eq!(area(&line.stroke_triangles(&StrokeStyle { cap: LineCap::Square, ..style.clone() })), 24.0);
+        let round = line.stroke_triangles(&StrokeStyle { cap: LineCap::Round, ..style.clone() });
+        // The round caps are inscribed polygons within the tolerance
+        assert_approx_eq!(area(&round), 20.0 + std::f32::consts::PI, 5e-2);
+
+        let corner = Path2::from_svg_path("M 0 0 L 10 0 L 10 10").unwrap();
+        let mesh = corner.stroke_triangles(&style);
+        area(&mesh);
+        let maxX = mesh.positions.iter().map(|p| p.x).fold(f32::MIN, f32::max);
+        assert_approx_eq!(maxX, 11.0);
+        assert!(mesh.normals.iter().all(|n| *n == Vec3::new(0.0, 0.0, 1.0)));
+
+        let dashed = line.stroke_triangles(&StrokeStyle { dashes: vec![1.0], ..style });
+        assert_approx_eq!(area(&dashed), 10.0, 1e-3);
+    }
+
+    #[test]
+    fn test_stroke_dots() {
+        // Zero-length dashes are drawn by their caps alone
+        let line = Path2::from_svg_path("M 0 0 H 10").unwrap();
+        let style = StrokeStyle { width: 2.0, cap: LineCap::Round, dashes: vec![0.0, 2.0], ..Default::default() };
+        let outline = line.stroke(&style);
+        let centers: Vec<Point2> = outline.subpaths().iter().map(|subpath| {
+            subpath.segments.iter().map(|segment| segment.bounding_box()).reduce(|a, b| a.union(&b)).unwrap().center()
+        }).collect();
+        assert_eq!(centers.len(), 6);
+        for (center, x) in centers.iter().zip([0.0, 2.0, 4.0, 6.0, 8.0, 10.0]) {
+            assert_approx_eq!(*center, Point2::new(x, 0.0), 1e-4);
+        }
+
+        let mesh = line.stroke_triangles(&style);
+        let area: f32 = mesh.indices.chunks(3).map(|triangle| {
+            let [a, b, c] = [0, 1, 2].map(|k| mesh.positions[triangle[k] as usize]);
+            0.5 * cross(b - a, c - a).z
+        }).sum();
+        // Inscribed polygons again, two caps per dot
+        assert_approx_eq!(area, 6.0 * std::f32::consts::PI, 0.3);
+
+        let square = line.stroke_triangles(&StrokeStyle { cap: LineCap::Square, ..style.clone() });
+        assert_eq!(square.indices.len(), 6 * 12);
+        assert!(line.stroke_triangles(&StrokeStyle { cap: LineCap::Butt, ..style.clone() }).indices.is_empty());
+        assert!(line.stroke(&StrokeStyle { cap: LineCap::Butt, ..style }).commands().is_empty());
+    }
+
+    #[test]
+    fn test_stroke_uvs() {
+        // v runs from 0 on the left of the stroke to 1 on its right, including in joins and caps
+        let style = StrokeStyle { width: 2.0, join: LineJoin::Miter, cap: LineCap::Square, ..Default::default() };
+        let line = Path2::from_svg_path("M 0 0 L 10 0").unwrap();
+        let mesh = line.stroke_triangles(&style);
+        for (p, uv) in mesh.positions.iter().zip(&mesh.uvs) {
+            assert_approx_eq!(uv.y, 0.5 - 0.5 * p.y);
+        }
+
+        // A left turn leaves its join gap on the right side
+        let corner = Path2::from_svg_path("M 0 0 L 10 0 L 10 10").unwrap();
+        for join in [LineJoin::Miter, LineJoin::Bevel, LineJoin::Round] {
+            let mesh = corner.stroke_triangles(&StrokeStyle { join, cap: LineCap::Butt, ..style.clone() });
+            let outer: Vec<_> = mesh.positions.iter().zip(&mesh.uvs).filter(|(p, _)| p.x > 10.5 || p.y < -0.5).collect();
+            assert!(!outer.is_empty());
+            assert!(outer.iter().all(|(_, uv)| uv.y == 1.0));
+        }
+
+        let round = line.stroke_triangles(&StrokeStyle { cap: LineCap::Round, ..style });
+        for (p, uv) in round.positions.iter().zip(&round.uvs) {
+            assert_approx_eq!(uv.y, 0.5 - 0.5 * p.y, 1e-5);
+        }
+    }
 }
@@ -0,0 +1,81 @@
+use std::ops::{BitAnd, BitOr, Not};
+
+// ##################################################
+// #                 BOOLEAN VECTORS                #
+// ##################################################
+
+macro_rules! impl_bvec {
+    ($name:ident, $($field:ident),+) => {
+        impl $name {
+            pub const fn new($($field: bool),+) -> Self {
+                $name { $($field),+ }
+            }
+
+            pub const fn splat(value: bool) -> Self {
+                $name { $($field: value),+ }
+            }
+
+            /// Whether any component is `true`
+            pub fn any(&self) -> bool {
+                false $(|| self.$field)+
+            }
+
+            /// Whether all components are `true`
+            pub fn all(&self) -> bool {
+                true $(&& self.$field)+
+            }
+        }
+
+        impl BitAnd for $name {
+            type Output = $name;
+
+            fn bitand(self, other: $name) -> $name {
+                $name { $($field: self.$field && other.$field),+ }
+            }
+        }
+
+        impl BitOr for $name {
+            type Output = $name;
+
+            fn bitor(self, other: $name) -> $name {
+                $name { $($field: self.$field || other.$field),+ }
+            }
+        }
+
+        impl Not for $name {
+            type Output = $name;
+
+            fn not(self) -> $name {
+                $name { $($field: !self.$field),+ }
+            }
+        }
+    };
+}
+
+/// Component-wise boolean mask of a `Vec2`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BVec2 {
+    pub x: bool,
+    pub y: bool,
+}
+
+/// Component-wise boolean mask of a `Vec3`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BVec3 {
+    pub x: bool,
+    pub y: bool,
+    pub z: bool,
+}
+
+/// Component-wise boolean mask of a `Vec4`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BVec4 {
+    pub x: bool,
+    pub y: bool,
+    pub z: bool,
+    pub w: bool,
+}
+
+impl_bvec!(BVec2, x, y);
+impl_bvec!(BVec3, x, y, z);
+impl_bvec!(BVec4, x, y, z, w);
@@ -1,10 +1,17 @@
 use std::ops::{Add, Sub, Mul, Div};
 use crate::vector::{Vec2, Vec3, Vec4};
+use crate::point::Point2;
 
-trait LinearTransformation {
+pub trait LinearTransformation {
     fn det(&self) -> f32;
 }
 
+/// Transformation of points and directions in the plane
+pub trait Transform2 {
+    fn transform_point2(&self, p: Point2) -> Point2;
+    fn transform_vector2(&self, v: Vec2) -> Vec2;
+}
+
 // ##################################################
 // #                   MATRIX 2D                    #
 // ##################################################
@@ -13,19 +20,19 @@ trait LinearTransformation {
 /// Representation of a 2x2 Matrix in row major
 #[repr(C)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Mat2 {
-    x00: f32,
-    x01: f32,
-    x10: f32,
-    x11: f32,
+    pub(crate) x00: f32,
+    pub(crate) x01: f32,
+    pub(crate) x10: f32,
+    pub(crate) x11: f32,
 }
 
 impl Mat2 {
     pub fn new(x00: f32, x01: f32, x10: f32, x11: f32) -> Self {
         Self {
-            x00: x00, x01: x01,
-            x10: x10, x11: x11
+            x00, x01,
+            x10, x11
         }
     }
 
@@ -50,7 +57,7 @@ impl Mat2 {
         match row {
             0 => Some(Vec2 { x: self.x00, y: self.x01 }),
             1 => Some(Vec2 { x: self.x10, y: self.x11 }),
-            _ => { println!("Row {} does not exist in the matrix!", row); return None; }
+            _ => { println!("Row {} does not exist in the matrix!", row); None }
         }
     }
 
@@ -84,8 +91,8 @@ impl Mat2 {
     }
 
    pub fn swapRows(&mut self, row1: usize, row2: usize) {
-        let tmp = self.getRow(row1).expect(format!("There is no row {} in the matrix!", row1).as_str());
-        self.setRow(row1, self.getRow(row2).expect(format!("There is no row {} in the matrix!", row2).as_str()));
+        let tmp = self.getRow(row1).unwrap_or_else(|| panic!("There is no row {} in the matrix!", row1));
+        self.setRow(row1, self.getRow(row2).unwrap_or_else(|| panic!("There is no row {} in the matrix!", row2)));
         self.setRow(row2, tmp);
    }
 }
@@ -141,24 +148,26 @@ impl Div<f32> for Mat2 {
 /// Representation of a 3x3 Matrix
 #[repr(C)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Mat3 {
-    x00: f32,
-    x01: f32,
-    x02: f32,
-    x10: f32,
-    x11: f32,
-    x12: f32,
-    x20: f32,
-    x21: f32,
-    x22: f32
+    pub(crate) x00: f32,
+    pub(crate) x01: f32,
+    pub(crate) x02: f32,
+    pub(crate) x10: f32,
+    pub(crate) x11: f32,
+    pub(crate) x12: f32,
+    pub(crate) x20: f32,
+    pub(crate) x21: f32,
+    pub(crate) x22: f32,
 }
 
 impl Mat3 {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(x00: f32, x01: f32, x02: f32, x10: f32, x11: f32, x12: f32, x20: f32, x21: f32, x22: f32) -> Self {
         Self { 
-            x00: x00, x01: x01, x02: x02, 
-            x10: x10, x11: x11, x12: x12, 
-            x20: x20, x21: x21, x22: x22 
+            x00, x01, x02, 
+            x10, x11, x12, 
+            x20, x21, x22 
         }
     }
 
@@ -231,8 +240,8 @@ impl Mat3 {
     }
 
     pub fn swapRows(&mut self, row1: usize, row2: usize) {
-        let tmp = self.getRow(row1).expect(format!("There is no row {} in the matrix!", row1).as_str());
-        self.setRow(row1, self.getRow(row2).expect(format!("There is no row {} in the matrix!", row2).as_str()));
+        let tmp = self.getRow(row1).unwrap_or_else(|| panic!("There is no row {} in the matrix!", row1));
+        self.setRow(row1, self.getRow(row2).unwrap_or_else(|| panic!("There is no row {} in the matrix!", row2)));
         self.setRow(row2, tmp);
     }
 }
@@ -244,6 +253,7 @@ impl Mat3 {
 /// Representation of a 4x4 Matrix
 #[repr(C)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Mat4 {
     pub x00: f32,
     pub x01: f32,
@@ -264,12 +274,13 @@ pub struct Mat4 {
 }
 
 impl Mat4 {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(x00: f32, x01: f32,x02: f32,x03: f32,x10: f32,x11: f32,x12: f32,x13: f32,x20: f32,x21: f32,x22: f32,x23: f32,x30: f32, x31: f32, x32: f32,x33: f32) -> Self {
         Self { 
-            x00: x00, x01: x01, x02: x02, x03: x03, 
-            x10: x10, x11: x11, x12: x12, x13: x13, 
-            x20: x20, x21: x21, x22: x22, x23: x23, 
-            x30: x30, x31: x31, x32: x32, x33: x33 
+            x00, x01, x02, x03, 
+            x10, x11, x12, x13, 
+            x20, x21, x22, x23, 
+            x30, x31, x32, x33 
         }
     }
 
@@ -360,6 +371,107 @@ impl Mat4 {
     }
 }
 
+// ##################################################
+// #                   AFFINE 2D                    #
+// ##################################################
+
+/// Affine transformation of the plane, a linear part followed by a translation
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Affine2 {
+    pub matrix2: Mat2,
+    pub translation: Vec2
+}
+
+impl Affine2 {
+    pub fn new(matrix2: Mat2, translation: Vec2) -> Self {
+        Self { matrix2, translation }
+    }
+
+    pub fn id() -> Self {
+        Self { matrix2: Mat2::id(), translation: Vec2::zero() }
+    }
+
+    pub fn from_translation(translation: Vec2) -> Self {
+        Self { matrix2: Mat2::id(), translation }
+    }
+
+    /// Counterclockwise rotation by `angle` radians around the origin
+    pub fn from_angle(angle: f32) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        Self { matrix2: Mat2::new(cos, -sin, sin, cos), translation: Vec2::zero() }
+    }
+
+    pub fn from_scale(scale: Vec2) -> Self {
+        Self { matrix2: Mat2::new(scale.x, 0.0, 0.0, scale.y), translation: Vec2::zero() }
+    }
+
+    /// The transformation as a 3x3 matrix acting on homogeneous coordinates
+    pub fn to_mat3(&self) -> Mat3 {
+        let m = &self.matrix2;
+        Mat3::new(
+            m.x00, m.x01, self.translation.x,
+            m.x10, m.x11, self.translation.y,
+            0.0, 0.0, 1.0
+        )
+    }
+
+    /// Inverse transformation, or `None` if the linear part is singular
+    pub fn inverse(&self) -> Option<Self> {
+        let det = self.matrix2.det();
+        if det == 0.0 {
+            return None;
+        }
+        let m = &self.matrix2;
+        let matrix2 = Mat2::new(m.x11 / det, -m.x01 / det, -m.x10 / det, m.x00 / det);
+        let inverse = Self { matrix2, translation: Vec2::zero() };
+        Some(Self { matrix2, translation: -inverse.transform_vector2(self.translation) })
+    }
+}
+
+/// Composition, `a * b` applies `b` first
+impl Mul<Affine2> for Affine2 {
+    type Output = Affine2;
+
+    fn mul(self, other: Affine2) -> Affine2 {
+        let (a, b) = (&self.matrix2, &other.matrix2);
+        Affine2 {
+            matrix2: Mat2::new(
+                a.x00 * b.x00 + a.x01 * b.x10, a.x00 * b.x01 + a.x01 * b.x11,
+                a.x10 * b.x00 + a.x11 * b.x10, a.x10 * b.x01 + a.x11 * b.x11
+            ),
+            translation: self.transform_vector2(other.translation) + self.translation
+        }
+    }
+}
+
+impl Transform2 for Affine2 {
+    fn transform_point2(&self, p: Point2) -> Point2 {
+        Point2::fromVec(self.transform_vector2(Vec2::fromPoint(p)) + self.translation)
+    }
+
+    fn transform_vector2(&self, v: Vec2) -> Vec2 {
+        let m = &self.matrix2;
+        Vec2::new(m.x00 * v.x + m.x01 * v.y, m.x10 * v.x + m.x11 * v.y)
+    }
+}
+
+/// A 3x3 matrix acts on points through homogeneous coordinates, so it may also be a projective transformation
+impl Transform2 for Mat3 {
+    fn transform_point2(&self, p: Point2) -> Point2 {
+        let w = self.x20 * p.x + self.x21 * p.y + self.x22;
+        Point2::new(
+            (self.x00 * p.x + self.x01 * p.y + self.x02) / w,
+            (self.x10 * p.x + self.x11 * p.y + self.x12) / w
+        )
+    }
+
+    fn transform_vector2(&self, v: Vec2) -> Vec2 {
+        Vec2::new(self.x00 * v.x + self.x01 * v.y, self.x10 * v.x + self.x11 * v.y)
+    }
+}
+
 // ##################################################
 // #              MATRIX FUNCTIONS                  #
 // ##################################################
@@ -0,0 +1,47 @@
+use crate::vector::{Vec2, Vec3};
+
+/// Indexed triangle mesh with per-vertex attributes, ready to be uploaded as vertex and index buffers
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Mesh {
+    pub positions: Vec<Vec3>,
+    pub normals: Vec<Vec3>,
+    pub uvs: Vec<Vec2>,
+    /// Counterclockwise triangles, three indices each
+    pub indices: Vec<u32>
+}
+
+impl Mesh {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn vertex_count(&self) -> usize {
+        self.positions.len()
+    }
+
+    pub fn triangle_count(&self) -> usize {
+        self.indices.len() / 3
+    }
+
+    pub fn push_vertex(&mut self, position: Vec3, normal: Vec3, uv: Vec2) -> u32 {
+        self.positions.push(position);
+        self.normals.push(normal);
+        self.uvs.push(uv);
+        (self.positions.len() - 1) as u32
+    }
+
+    /// Triangulates a grid of `rows × cols` vertices stored row by row starting at `first`.
+    /// Each quad `(r, c)` becomes two triangles that are counterclockwise when rows run along the
+    /// first parameter direction and columns along the second.
+    pub(crate) fn push_grid_indices(&mut self, first: u32, rows: usize, cols: usize) {
+        for r in 0..rows.saturating_sub(1) {
+            for c in 0..cols.saturating_sub(1) {
+                let i00 = first + (r * cols + c) as u32;
+                let i01 = i00 + 1;
+                let i10 = i00 + cols as u32;
+                let i11 = i10 + 1;
+                self.indices.extend_from_slice(&[i00, i10, i11, i00, i11, i01]);
+            }
+        }
+    }
+}
@@ -0,0 +1,258 @@
+use crate::point::Point3;
+use crate::vector::{Vec2, Vec3, Vec4, InnerSpace, cross};
+use crate::bspline::BSpline;
+use crate::mesh::Mesh;
+
+fn toHomogeneous(p: Vec3, w: f32) -> Vec4 {
+    Vec4 { x: p.x * w, y: p.y * w, z: p.z * w, w }
+}
+
+fn fromHomogeneous(h: Vec4) -> Vec3 {
+    Vec3 { x: h.x / h.w, y: h.y / h.w, z: h.z / h.w }
+}
+
+/// Derivative of the projected point `C = A / w` given the homogeneous point and its derivative
+fn projectedDerivative(h: Vec4, dh: Vec4) -> Vec3 {
+    (dh.xyz() - fromHomogeneous(h) * dh.w) / h.w
+}
+
+// ##################################################
+// #                  NURBS CURVE                   #
+// ##################################################
+
+/// Non-uniform rational B-spline curve in R³, stored as a B-spline over homogeneous control points
+#[derive(Debug, Clone, PartialEq)]
+pub struct NurbsCurve {
+    homogeneous: BSpline<Vec4>
+}
+
+impl NurbsCurve {
+    /// Curve with an explicit knot vector and one positive weight per control point.
+    /// Panics if the counts do not fit together, see `BSpline::new`.
+    pub fn new(degree: usize, knots: Vec<f32>, points: Vec<Vec3>, weights: Vec<f32>) -> Self {
+        assert_eq!(points.len(), weights.len(), "Every control point needs exactly one weight!");
+        assert!(weights.iter().all(|w| *w > 0.0), "Weights of a NURBS curve must be positive!");
+        let homogeneous = points.iter().zip(&weights).map(|(p, w)| toHomogeneous(*p, *w)).collect();
+        Self { homogeneous: BSpline::new(degree, knots, homogeneous) }
+    }
+
+    /// The polynomial spline as a NURBS curve with unit weights
+    pub fn from_bspline(spline: &BSpline<Vec3>) -> Self {
+        Self::new(spline.degree(), spline.knots().to_vec(), spline.points().to_vec(), vec![1.0; spline.points().len()])
+    }
+
+    pub fn degree(&self) -> usize {
+        self.homogeneous.degree()
+    }
+
+    pub fn knots(&self) -> &[f32] {
+        self.homogeneous.knots()
+    }
+
+    pub fn points(&self) -> Vec<Vec3> {
+        self.homogeneous.points().iter().map(|h| fromHomogeneous(*h)).collect()
+    }
+
+    pub fn weights(&self) -> Vec<f32> {
+        self.homogeneous.points().iter().map(|h| h.w).collect()
+    }
+
+    pub fn domain(&self) -> (f32, f32) {
+        self.homogeneous.domain()
+    }
+
+    pub fn eval(&self, u: f32) -> Point3 {
+        Point3::fromVec(fromHomogeneous(self.homogeneous.eval(u)))
+    }
+
+    /// First derivative with respect to `u`
+    pub fn velocity(&self, u: f32) -> Vec3 {
+        projectedDerivative(self.homogeneous.eval(u), self.homogeneous.velocity(u))
+    }
+
+    /// Second derivative with respect to `u`, `C'' = (A'' - 2·w'·C' - w''·C) / w`
+    pub fn acceleration(&self, u: f32) -> Vec3 {
+        let derivative = self.homogeneous.derivative();
+        let h = self.homogeneous.eval(u);
+        let dh = derivative.eval(u);
+        let ddh = derivative.velocity(u);
+        let c = fromHomogeneous(h);
+        let dc = projectedDerivative(h, dh);
+        (ddh.xyz() - dc * (2.0 * dh.w) - c * ddh.w) / h.w
+    }
+
+    /// Unit tangent at `u`, or zero where the curve is degenerate
+    pub fn tangent(&self, u: f32) -> Vec3 {
+        self.velocity(u).normalize_or_zero()
+    }
+
+    /// Inserts the knot `u` once without changing the shape of the curve
+    pub fn insert_knot(&mut self, u: f32) {
+        self.homogeneous.insert_knot(u);
+    }
+
+    /// Samples `segments + 1` points spaced uniformly in the parameter over the domain
+    pub fn tessellate(&self, segments: usize) -> Vec<Point3> {
+        let (start, end) = self.domain();
+        let segments = segments.max(1);
+        (0..=segments).map(|i| self.eval(start + (end - start) * i as f32 / segments as f32)).collect()
+    }
+}
+
+// ##################################################
+// #                 NURBS SURFACE                  #
+// ##################################################
+
+/// Non-uniform rational B-spline surface in R³ over a grid of weighted control points.
+/// Rows of the grid follow the `u` direction and columns the `v` direction.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NurbsSurface {
+    degreeU: usize,
+    degreeV: usize,
+    knotsU: Vec<f32>,
+    knotsV: Vec<f32>,
+    /// Homogeneous control points stored row by row
+    points: Vec<Vec4>,
+    rows: usize,
+    cols: usize
+}
+
+impl NurbsSurface {
+    /// Surface over the control grid `grid[i][j]` with the matching `weights[i][j]`,
+    /// where `i` runs along `u` and `j` along `v`.
+    /// Panics if the grid is not rectangular or does not fit the degrees and knot vectors.
+    pub fn new(degree_u: usize, degree_v: usize, knots_u: Vec<f32>, knots_v: Vec<f32>, grid: Vec<Vec<Vec3>>, weights: Vec<Vec<f32>>) -> Self {
+        let rows = grid.len();
+        let cols = grid.first().map_or(0, |row| row.len());
+        assert!(rows > 0 && cols > 0, "A NURBS surface needs at least one control point!");
+        assert!(grid.iter().all(|row| row.len() == cols), "All rows of a NURBS surface need the same number of control points!");
+        assert!(weights.len() == rows && weights.iter().all(|row| row.len() == cols), "Every control point needs exactly one weight!");
+        assert!(weights.iter().flatten().all(|w| *w > 0.0), "Weights of a NURBS surface must be positive!");
+
+        let points = grid.iter().flatten().zip(weights.iter().flatten()).map(|(p, w)| toHomogeneous(*p, *w)).collect();
+        let surface = Self { degreeU: degree_u, degreeV: degree_v, knotsU: knots_u, knotsV: knots_v, points, rows, cols };
+        // Building one spline per direction validates the knot vectors against the grid
+        surface.columnSpline(0);
+        surface.rowSpline(0);
+        surface
+    }
+
+    pub fn degree_u(&self) -> usize {
+        self.degreeU
+    }
+
+    pub fn degree_v(&self) -> usize {
+        self.degreeV
+    }
+
+    pub fn knots_u(&self) -> &[f32] {
+        &self.knotsU
+    }
+
+    pub fn knots_v(&self) -> &[f32] {
+        &self.knotsV
+    }
+
+    /// Number of control points along `u` and along `v`
+    pub fn grid_size(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    pub fn control_point(&self, i: usize, j: usize) -> Vec3 {
+        fromHomogeneous(self.points[i * self.cols + j])
+    }
+
+    pub fn weight(&self, i: usize, j: usize) -> f32 {
+        self.points[i * self.cols + j].w
+    }
+
+    /// Parameter ranges over which the surface is defined, for `u` and for `v`
+    pub fn domain(&self) -> ((f32, f32), (f32, f32)) {
+        ((self.knotsU[self.degreeU], self.knotsU[self.rows]), (self.knotsV[self.degreeV], self.knotsV[self.cols]))
+    }
+
+    fn rowSpline(&self, i: usize) -> BSpline<Vec4> {
+        BSpline::new(self.degreeV, self.knotsV.clone(), self.points[i * self.cols..(i + 1) * self.cols].to_vec())
+    }
+
+    fn columnSpline(&self, j: usize) -> BSpline<Vec4> {
+        BSpline::new(self.degreeU, self.knotsU.clone(), (0..self.rows).map(|i| self.points[i * self.cols + j]).collect())
+    }
+
+    /// Collapses every row at `v`, leaving the homogeneous spline running along `u`
+    fn splineAlongU(&self, v: f32) -> BSpline<Vec4> {
+        BSpline::new(self.degreeU, self.knotsU.clone(), (0..self.rows).map(|i| self.rowSpline(i).eval(v)).collect())
+    }
+
+    /// Collapses every column at `u`, leaving the homogeneous spline running along `v`
+    fn splineAlongV(&self, u: f32) -> BSpline<Vec4> {
+        BSpline::new(self.degreeV, self.knotsV.clone(), (0..self.cols).map(|j| self.columnSpline(j).eval(u)).collect())
+    }
+
+    pub fn eval(&self, u: f32, v: f32) -> Point3 {
+        Point3::fromVec(fromHomogeneous(self.splineAlongU(v).eval(u)))
+    }
+
+    /// Partial derivative with respect to `u`
+    pub fn derivative_u(&self, u: f32, v: f32) -> Vec3 {
+        let spline = self.splineAlongU(v);
+        projectedDerivative(spline.eval(u), spline.velocity(u))
+    }
+
+    /// Partial derivative with respect to `v`
+    pub fn derivative_v(&self, u: f32, v: f32) -> Vec3 {
+        let spline = self.splineAlongV(u);
+        projectedDerivative(spline.eval(v), spline.velocity(v))
+    }
+
+    /// Unit surface normal `∂u × ∂v`, or zero where the surface is degenerate
+    pub fn normal(&self, u: f32, v: f32) -> Vec3 {
+        cross(self.derivative_u(u, v), self.derivative_v(u, v)).normalize_or_zero()
+    }
+
+    /// Inserts the knot `u` once into the `u` direction without changing the shape of the surface
+    pub fn insert_knot_u(&mut self, u: f32) {
+        let columns: Vec<BSpline<Vec4>> = (0..self.cols).map(|j| {
+            let mut spline = self.columnSpline(j);
+            spline.insert_knot(u);
+            spline
+        }).collect();
+
+        self.rows += 1;
+        self.knotsU = columns[0].knots().to_vec();
+        self.points = (0..self.rows).flat_map(|i| columns.iter().map(move |c| c.points()[i])).collect();
+    }
+
+    /// Inserts the knot `v` once into the `v` direction without changing the shape of the surface
+    pub fn insert_knot_v(&mut self, v: f32) {
+        let rows: Vec<BSpline<Vec4>> = (0..self.rows).map(|i| {
+            let mut spline = self.rowSpline(i);
+            spline.insert_knot(v);
+            spline
+        }).collect();
+
+        self.cols += 1;
+        self.knotsV = rows[0].knots().to_vec();
+        self.points = rows.iter().flat_map(|r| r.points().to_vec()).collect();
+    }
+
+    /// Samples the surface on a regular `(u_segments + 1) × (v_segments + 1)` grid over its domain
+    /// with positions, normals and texture coordinates normalized to `[0, 1]`
+    pub fn tessellate(&self, u_segments: usize, v_segments: usize) -> Mesh {
+        let ((u0, u1), (v0, v1)) = self.domain();
+        let uSegments = u_segments.max(1);
+        let vSegments = v_segments.max(1);
+        let mut mesh = Mesh::new();
+        for i in 0..=uSegments {
+            let s = i as f32 / uSegments as f32;
+            let u = u0 + (u1 - u0) * s;
+            for j in 0..=vSegments {
+                let t = j as f32 / vSegments as f32;
+                let v = v0 + (v1 - v0) * t;
+                mesh.push_vertex(Vec3::fromPoint(self.eval(u, v)), self.normal(u, v), Vec2::new(s, t));
+            }
+        }
+        mesh.push_grid_indices(0, uSegments + 1, vSegments + 1);
+        mesh
+    }
+}
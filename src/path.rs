@@ -0,0 +1,633 @@
+use std::f32::consts::{FRAC_PI_2, TAU};
+use crate::bezier::Bezier2;
+use crate::bounds::Aabb2;
+use crate::matrix::Transform2;
+use crate::point::Point2;
+use crate::vector::Vec2;
+
+// ##################################################
+// #                 PATH COMMANDS                  #
+// ##################################################
+
+/// Drawing command of a `Path2`, all points are absolute
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PathCommand {
+    MoveTo(Point2),
+    LineTo(Point2),
+    QuadTo(Point2, Point2),
+    CubicTo(Point2, Point2, Point2),
+    /// Elliptical arc in the endpoint form of SVG, with the rotation of the ellipse in radians
+    ArcTo { radii: Vec2, x_rotation: f32, large_arc: bool, sweep: bool, to: Point2 },
+    Close
+}
+
+impl PathCommand {
+    fn endPoint(&self) -> Option<Point2> {
+        match *self {
+            PathCommand::MoveTo(p) | PathCommand::LineTo(p) | PathCommand::QuadTo(_, p) | PathCommand::CubicTo(_, _, p) => Some(p),
+            PathCommand::ArcTo { to, .. } => Some(to),
+            PathCommand::Close => None
+        }
+    }
+}
+
+/// Control points of the cubic Béziers approximating an elliptical arc in SVG endpoint form.
+/// Follows the conversion to center form from the SVG implementation notes and uses one cubic per quarter turn.
+pub(crate) fn arcToCubics(from: Vec2, radii: Vec2, xRotation: f32, largeArc: bool, sweep: bool, to: Vec2) -> Vec<[Vec2; 4]> {
+    if from == to {
+        return Vec::new();
+    }
+    let (mut rx, mut ry) = (radii.x.abs(), radii.y.abs());
+    if rx == 0.0 || ry == 0.0 {
+        let step = (to - from) / 3.0;
+        return vec![[from, from + step, to - step, to]];
+    }
+
+    let (sin, cos) = xRotation.sin_cos();
+    let half = (from - to) * 0.5;
+    let x1 = cos * half.x + sin * half.y;
+    let y1 = -sin * half.x + cos * half.y;
+
+    // Radii that are too small are scaled up until the arc fits
+    let lambda = (x1 * x1) / (rx * rx) + (y1 * y1) / (ry * ry);
+    if lambda > 1.0 {
+        rx *= lambda.sqrt();
+        ry *= lambda.sqrt();
+    }
+
+    let numerator = rx * rx * ry * ry - rx * rx * y1 * y1 - ry * ry * x1 * x1;
+    let denominator = rx * rx * y1 * y1 + ry * ry * x1 * x1;
+    let mut coefficient = (numerator / denominator).max(0.0).sqrt();
+    if largeArc == sweep {
+        coefficient = -coefficient;
+    }
+    let cx1 = coefficient * rx * y1 / ry;
+    let cy1 = -coefficient * ry * x1 / rx;
+    let mid = (from + to) * 0.5;
+    let center = Vec2::new(cos * cx1 - sin * cy1 + mid.x, sin * cx1 + cos * cy1 + mid.y);
+
+    let start = ((y1 - cy1) / ry).atan2((x1 - cx1) / rx);
+    let end = ((-y1 - cy1) / ry).atan2((-x1 - cx1) / rx);
+    let mut delta = end - start;
+    if sweep && delta < 0.0 {
+        delta += TAU;
+    } else if !sweep && delta > 0.0 {
+        delta -= TAU;
+    }
+
+    let pieces = (delta.abs() / FRAC_PI_2 - 1e-4).ceil().max(1.0) as usize;
+    let step = delta / pieces as f32;
+    let k = 4.0 / 3.0 * (step / 4.0).tan();
+    let rotate = |v: Vec2| Vec2::new(cos * v.x - sin * v.y, sin * v.x + cos * v.y);
+    let at = |angle: f32| center + rotate(Vec2::new(rx * angle.cos(), ry * angle.sin()));
+    let derivative = |angle: f32| rotate(Vec2::new(-rx * angle.sin(), ry * angle.cos()));
+
+    (0..pieces).map(|i| {
+        let a0 = start + step * i as f32;
+        let a1 = a0 + step;
+        let p0 = if i == 0 { from } else { at(a0) };
+        let p3 = if i + 1 == pieces { to } else { at(a1) };
+        [p0, p0 + derivative(a0) * k, p3 - derivative(a1) * k, p3]
+    }).collect()
+}
+
+// ##################################################
+// #                     PATH                       #
+// ##################################################
+
+/// Subpath as its start point, the drawing commands with the point each one starts from, and whether it is closed
+type SubpathCommands = (Point2, Vec<(Point2, PathCommand)>, bool);
+
+/// Connected segments of a path, see `Path2::subpaths`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Subpath {
+    pub segments: Vec<Bezier2>,
+    pub closed: bool
+}
+
+/// 2D path made of lines, Bézier curves and elliptical arcs, possibly with several subpaths.
+/// Like in SVG, a drawing command without a preceding `move_to` continues from the current point,
+/// which is the origin for an empty path and the start of the subpath after `close`.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Path2 {
+    commands: Vec<PathCommand>
+}
+
+impl Path2 {
+    pub fn new() -> Self {
+        Self { commands: Vec::new() }
+    }
+
+    pub fn commands(&self) -> &[PathCommand] {
+        &self.commands
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.commands.is_empty()
+    }
+
+    /// Starts a new subpath at `p`
+    pub fn move_to(&mut self, p: Point2) -> &mut Self {
+        self.commands.push(PathCommand::MoveTo(p));
+        self
+    }
+
+    pub fn line_to(&mut self, p: Point2) -> &mut Self {
+        self.commands.push(PathCommand::LineTo(p));
+        self
+    }
+
+    /// Quadratic Bézier from the current point to `to`
+    pub fn quad_to(&mut self, control: Point2, to: Point2) -> &mut Self {
+        self.commands.push(PathCommand::QuadTo(control, to));
+        self
+    }
+
+    /// Cubic Bézier from the current point to `to`
+    pub fn cubic_to(&mut self, control1: Point2, control2: Point2, to: Point2) -> &mut Self {
+        self.commands.push(PathCommand::CubicTo(control1, control2, to));
+        self
+    }
+
+    /// Elliptical arc from the current point to `to` with the ellipse rotated by `x_rotation` radians.
+    /// Of the four possible arcs, `large_arc` picks the one spanning more than 180° and `sweep` the counterclockwise one.
+    /// Radii that are too small to reach `to` are scaled up, and a zero radius gives a straight line.
+    pub fn arc_to(&mut self, radii: Vec2, x_rotation: f32, large_arc: bool, sweep: bool, to: Point2) -> &mut Self {
+        self.commands.push(PathCommand::ArcTo { radii, x_rotation, large_arc, sweep, to });
+        self
+    }
+
+    /// Closes the current subpath with a straight line back to its start
+    pub fn close(&mut self) -> &mut Self {
+        self.commands.push(PathCommand::Close);
+        self
+    }
+
+    fn subpathCommands(&self) -> Vec<SubpathCommands> {
+        let mut subpaths = Vec::new();
+        let mut start = Point2::origin();
+        let mut cursor = start;
+        let mut current = Vec::new();
+
+        for command in &self.commands {
+            match command {
+                PathCommand::MoveTo(p) => {
+                    if !current.is_empty() {
+                        subpaths.push((start, std::mem::take(&mut current), false));
+                    }
+                    start = *p;
+                    cursor = *p;
+                }
+                PathCommand::Close => {
+                    if !current.is_empty() {
+                        subpaths.push((start, std::mem::take(&mut current), true));
+                    }
+                    cursor = start;
+                }
+                _ => {
+                    current.push((cursor, *command));
+                    cursor = command.endPoint().unwrap_or(cursor);
+                }
+            }
+        }
+        if !current.is_empty() {
+            subpaths.push((start, current, false));
+        }
+        subpaths
+    }
+
+    /// Segments of every subpath as Bézier curves.
+    /// Lines become curves of degree 1, arcs become cubics, and closed subpaths end with the closing line.
+    pub fn subpaths(&self) -> Vec<Subpath> {
+        self.subpathCommands().into_iter().map(|(start, commands, closed)| {
+            let mut segments = Vec::new();
+            let mut cursor = start;
+            for (from, command) in commands {
+                let from = Vec2::fromPoint(from);
+                match command {
+                    PathCommand::LineTo(p) => segments.push(Bezier2::new(vec![from, Vec2::fromPoint(p)])),
+                    PathCommand::QuadTo(c, p) => segments.push(Bezier2::new(vec![from, Vec2::fromPoint(c), Vec2::fromPoint(p)])),
+                    PathCommand::CubicTo(c1, c2, p) => {
+                        segments.push(Bezier2::new(vec![from, Vec2::fromPoint(c1), Vec2::fromPoint(c2), Vec2::fromPoint(p)]));
+                    }
+                    PathCommand::ArcTo { radii, x_rotation, large_arc, sweep, to } => {
+                        segments.extend(arcToCubics(from, radii, x_rotation, large_arc, sweep, Vec2::fromPoint(to))
+                            .into_iter().map(|points| Bezier2::new(points.to_vec())));
+                    }
+                    PathCommand::MoveTo(_) | PathCommand::Close => unreachable!()
+                }
+                cursor = command.endPoint().unwrap_or(cursor);
+            }
+            if closed && cursor != start {
+                segments.push(Bezier2::new(vec![Vec2::fromPoint(cursor), Vec2::fromPoint(start)]));
+            }
+            Subpath { segments, closed }
+        }).collect()
+    }
+
+    /// All segments of all subpaths in drawing order
+    pub fn segments(&self) -> impl Iterator<Item = Bezier2> {
+        self.subpaths().into_iter().flat_map(|subpath| subpath.segments)
+    }
+
+    /// Total arc length of all subpaths
+    pub fn length(&self) -> f32 {
+        self.segments().map(|segment| segment.arc_length()).sum()
+    }
+
+    /// Tight bounding box of the drawn segments, or `None` if nothing is drawn
+    pub fn bounding_box(&self) -> Option<Aabb2> {
+        self.segments().map(|segment| segment.bounding_box()).reduce(|a, b| a.union(&b))
+    }
+
+    /// Segment and curve parameter at `length` along the path, clamped to the ends
+    fn locate(segments: &[Bezier2], lengths: &[f32], length: f32) -> Option<(usize, f32)> {
+        let mut remaining = length.max(0.0);
+        for (i, segmentLength) in lengths.iter().enumerate() {
+            if remaining <= *segmentLength || i + 1 == lengths.len() {
+                return Some((i, segments[i].t_at_length(remaining.min(*segmentLength))));
+            }
+            remaining -= segmentLength;
+        }
+        None
+    }
+
+    /// Point at `length` along the path, clamped to its ends.
+    /// The length runs through the subpaths in order without counting the jumps between them.
+    pub fn point_at_length(&self, length: f32) -> Option<Point2> {
+        let segments: Vec<Bezier2> = self.segments().collect();
+        let lengths: Vec<f32> = segments.iter().map(|segment| segment.arc_length()).collect();
+        Self::locate(&segments, &lengths, length).map(|(i, t)| segments[i].eval(t))
+    }
+
+    /// Unit tangent at `length` along the path, see `point_at_length`
+    pub fn tangent_at_length(&self, length: f32) -> Option<Vec2> {
+        let segments: Vec<Bezier2> = self.segments().collect();
+        let lengths: Vec<f32> = segments.iter().map(|segment| segment.arc_length()).collect();
+        Self::locate(&segments, &lengths, length).map(|(i, t)| segments[i].tangent(t))
+    }
+
+    /// Points spaced `spacing` apart along the path, starting at its beginning.
+    /// Panics if `spacing` is not positive.
+    pub fn sample_by_distance(&self, spacing: f32) -> Vec<Point2> {
+        assert!(spacing > 0.0, "The sample spacing must be positive!");
+        let segments: Vec<Bezier2> = self.segments().collect();
+        let lengths: Vec<f32> = segments.iter().map(|segment| segment.arc_length()).collect();
+        let total: f32 = lengths.iter().sum();
+
+        let count = (total / spacing).floor() as usize;
+        (0..=count).filter_map(|i| {
+            Self::locate(&segments, &lengths, i as f32 * spacing).map(|(segment, t)| segments[segment].eval(t))
+        }).collect()
+    }
+
+    /// The same shape traversed backwards, with the subpaths in reverse order
+    pub fn reverse(&self) -> Self {
+        let mut reversed = Self::new();
+        for (start, commands, closed) in self.subpathCommands().into_iter().rev() {
+            let end = commands.last().and_then(|(_, command)| command.endPoint()).unwrap_or(start);
+            reversed.move_to(end);
+            for (from, command) in commands.into_iter().rev() {
+                match command {
+                    PathCommand::LineTo(_) => reversed.line_to(from),
+                    PathCommand::QuadTo(c, _) => reversed.quad_to(c, from),
+                    PathCommand::CubicTo(c1, c2, _) => reversed.cubic_to(c2, c1, from),
+                    PathCommand::ArcTo { radii, x_rotation, large_arc, sweep, .. } => reversed.arc_to(radii, x_rotation, large_arc, !sweep, from),
+                    PathCommand::MoveTo(_) | PathCommand::Close => unreachable!()
+                };
+            }
+            if closed {
+                reversed.close();
+            }
+        }
+        reversed
+    }
+
+    /// Path with every control point transformed by an `Affine2` or a `Mat3`.
+    /// Arcs are converted to cubics first. The result is exact for affine maps, while a projective `Mat3` only maps the control points.
+    pub fn transform<T: Transform2>(&self, transform: &T) -> Self {
+        let map = |p: Point2| transform.transform_point2(p);
+        let mut transformed = Self::new();
+        for (start, commands, closed) in self.subpathCommands() {
+            transformed.move_to(map(start));
+            for (from, command) in commands {
+                match command {
+                    PathCommand::LineTo(p) => { transformed.line_to(map(p)); }
+                    PathCommand::QuadTo(c, p) => { transformed.quad_to(map(c), map(p)); }
+                    PathCommand::CubicTo(c1, c2, p) => { transformed.cubic_to(map(c1), map(c2), map(p)); }
+                    PathCommand::ArcTo { radii, x_rotation, large_arc, sweep, to } => {
+                        for [_, c1, c2, p] in arcToCubics(Vec2::fromPoint(from), radii, x_rotation, large_arc, sweep, Vec2::fromPoint(to)) {
+                            transformed.cubic_to(map(Point2::fromVec(c1)), map(Point2::fromVec(c2)), map(Point2::fromVec(p)));
+                        }
+                    }
+                    PathCommand::MoveTo(_) | PathCommand::Close => unreachable!()
+                }
+            }
+            if closed {
+                transformed.close();
+            }
+        }
+        transformed
+    }
+}
+
+// ##################################################
+// #                 SVG PATH DATA                  #
+// ##################################################
+
+/// What went wrong while parsing SVG path data
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SvgPathErrorKind {
+    /// Path data has to start with a move-to command
+    ExpectedMoveTo,
+    ExpectedNumber,
+    /// Arc flags have to be `0` or `1`
+    ExpectedFlag,
+    /// A character that is not a path command where a command was expected
+    UnexpectedCharacter(char),
+    UnexpectedEnd
+}
+
+/// Error from `Path2::from_svg_path` with the character offset into the path data where it occurred
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SvgPathError {
+    pub offset: usize,
+    pub kind: SvgPathErrorKind
+}
+
+impl std::fmt::Display for SvgPathError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.kind {
+            SvgPathErrorKind::ExpectedMoveTo => write!(f, "expected a move-to command at offset {}", self.offset),
+            SvgPathErrorKind::ExpectedNumber => write!(f, "expected a number at offset {}", self.offset),
+            SvgPathErrorKind::ExpectedFlag => write!(f, "expected an arc flag at offset {}", self.offset),
+            SvgPathErrorKind::UnexpectedCharacter(c) => write!(f, "unexpected character '{}' at offset {}", c, self.offset),
+            SvgPathErrorKind::UnexpectedEnd => write!(f, "unexpected end of path data at offset {}", self.offset)
+        }
+    }
+}
+
+impl std::error::Error for SvgPathError {}
+
+/// Tokenizer for SVG path data.
+/// Everything accepted before an error is ASCII, so byte offsets are also character offsets.
+struct SvgPathParser<'a> {
+    data: &'a str,
+    pos: usize
+}
+
+impl SvgPathParser<'_> {
+    fn peek(&self) -> Option<u8> {
+        self.data.as_bytes().get(self.pos).copied()
+    }
+
+    fn error(&self, kind: SvgPathErrorKind) -> SvgPathError {
+        let kind = match (kind, self.peek()) {
+            (SvgPathErrorKind::ExpectedNumber | SvgPathErrorKind::ExpectedFlag, None) => SvgPathErrorKind::UnexpectedEnd,
+            _ => kind
+        };
+        SvgPathError { offset: self.pos, kind }
+    }
+
+    fn skipWhitespace(&mut self) {
+        while matches!(self.peek(), Some(b' ' | b'\t' | b'\n' | b'\r' | b'\x0C')) {
+            self.pos += 1;
+        }
+    }
+
+    /// Skips whitespace with at most one comma in it, returning whether there was a comma
+    fn skipSeparator(&mut self) -> bool {
+        self.skipWhitespace();
+        let comma = self.peek() == Some(b',');
+        if comma {
+            self.pos += 1;
+            self.skipWhitespace();
+        }
+        comma
+    }
+
+    fn atNumber(&self) -> bool {
+        matches!(self.peek(), Some(b'0'..=b'9' | b'.' | b'+' | b'-'))
+    }
+
+    fn skipDigits(&mut self) -> usize {
+        let start = self.pos;
+        while matches!(self.peek(), Some(b'0'..=b'9')) {
+            self.pos += 1;
+        }
+        self.pos - start
+    }
+
+    fn number(&mut self) -> Result<f32, SvgPathError> {
+        let start = self.pos;
+        if matches!(self.peek(), Some(b'+' | b'-')) {
+            self.pos += 1;
+        }
+        let mut digits = self.skipDigits();
+        if self.peek() == Some(b'.') {
+            self.pos += 1;
+            digits += self.skipDigits();
+        }
+        if digits == 0 {
+            self.pos = start;
+            return Err(self.error(SvgPathErrorKind::ExpectedNumber));
+        }
+
+        // Only consume an exponent if digits follow, so "2e" stays an error at the 'e'
+        if matches!(self.peek(), Some(b'e' | b'E')) {
+            let mantissaEnd = self.pos;
+            self.pos += 1;
+            if matches!(self.peek(), Some(b'+' | b'-')) {
+                self.pos += 1;
+            }
+            if self.skipDigits() == 0 {
+                self.pos = mantissaEnd;
+            }
+        }
+        self.data[start..self.pos].parse().map_err(|_| SvgPathError { offset: start, kind: SvgPathErrorKind::ExpectedNumber })
+    }
+
+    /// Numbers of one argument set, separated by whitespace or commas
+    fn numbers<const N: usize>(&mut self) -> Result<[f32; N], SvgPathError> {
+        let mut values = [0.0; N];
+        for (i, value) in values.iter_mut().enumerate() {
+            if i > 0 {
+                self.skipSeparator();
+            }
+            *value = self.number()?;
+        }
+        Ok(values)
+    }
+
+    fn flag(&mut self) -> Result<bool, SvgPathError> {
+        self.skipSeparator();
+        match self.peek() {
+            Some(b'0') => { self.pos += 1; Ok(false) }
+            Some(b'1') => { self.pos += 1; Ok(true) }
+            _ => Err(self.error(SvgPathErrorKind::ExpectedFlag))
+        }
+    }
+
+    /// Moves to the next argument set of the current command, or returns `false` if a new command follows
+    fn nextArguments(&mut self) -> Result<bool, SvgPathError> {
+        let comma = self.skipSeparator();
+        if self.atNumber() {
+            return Ok(true);
+        }
+        if comma {
+            return Err(self.error(SvgPathErrorKind::ExpectedNumber));
+        }
+        Ok(false)
+    }
+}
+
+/// Formats a coordinate with the shortest representation that parses back to the same value
+fn svgNumber(value: f32) -> String {
+    format!("{}", value)
+}
+
+impl Path2 {
+    /// Parses SVG path data like `"M 0 0 C 1 2, 3 2, 4 0 Z"`.
+    /// All absolute and relative commands are supported, including the shorthands `S` and `T` and elliptical arcs,
+    /// which are kept as `ArcTo` commands and become cubic Béziers in `segments`.
+    pub fn from_svg_path(data: &str) -> Result<Self, SvgPathError> {
+        let mut parser = SvgPathParser { data, pos: 0 };
+        let mut path = Self::new();
+        let mut current = Point2::origin();
+        let mut start = current;
+        // Last control point of the previous command, used for reflection by S and T
+        let mut lastCubic: Option<Point2> = None;
+        let mut lastQuad: Option<Point2> = None;
+
+        parser.skipWhitespace();
+        while let Some(letter) = parser.peek() {
+            let command = letter.to_ascii_uppercase();
+            let relative = letter.is_ascii_lowercase();
+            if !b"MLHVCSQTAZ".contains(&command) || !letter.is_ascii_alphabetic() {
+                let c = data[parser.pos..].chars().next().unwrap_or_default();
+                return Err(parser.error(SvgPathErrorKind::UnexpectedCharacter(c)));
+            }
+            if path.is_empty() && command != b'M' {
+                return Err(parser.error(SvgPathErrorKind::ExpectedMoveTo));
+            }
+            parser.pos += 1;
+            parser.skipWhitespace();
+
+            if command == b'Z' {
+                path.close();
+                current = start;
+                lastCubic = None;
+                lastQuad = None;
+                continue;
+            }
+
+            let mut first = true;
+            loop {
+                let origin = if relative { Vec2::fromPoint(current) } else { Vec2::zero() };
+                let at = |x: f32, y: f32| Point2::fromVec(origin + Vec2::new(x, y));
+                let (mut cubic, mut quad) = (None, None);
+
+                match command {
+                    b'M' => {
+                        let [x, y] = parser.numbers()?;
+                        current = at(x, y);
+                        // Further coordinate pairs after a move-to are implicit line-tos
+                        if first {
+                            path.move_to(current);
+                            start = current;
+                        } else {
+                            path.line_to(current);
+                        }
+                    }
+                    b'L' => {
+                        let [x, y] = parser.numbers()?;
+                        current = at(x, y);
+                        path.line_to(current);
+                    }
+                    b'H' => {
+                        let [x] = parser.numbers()?;
+                        current = Point2::new(x + origin.x, current.y);
+                        path.line_to(current);
+                    }
+                    b'V' => {
+                        let [y] = parser.numbers()?;
+                        current = Point2::new(current.x, y + origin.y);
+                        path.line_to(current);
+                    }
+                    b'C' => {
+                        let [x1, y1, x2, y2, x, y] = parser.numbers()?;
+                        let control2 = at(x2, y2);
+                        path.cubic_to(at(x1, y1), control2, at(x, y));
+                        current = at(x, y);
+                        cubic = Some(control2);
+                    }
+                    b'S' => {
+                        let [x2, y2, x, y] = parser.numbers()?;
+                        let control1 = lastCubic.map_or(current, |c| current + (current - c));
+                        let control2 = at(x2, y2);
+                        path.cubic_to(control1, control2, at(x, y));
+                        current = at(x, y);
+                        cubic = Some(control2);
+                    }
+                    b'Q' => {
+                        let [x1, y1, x, y] = parser.numbers()?;
+                        let control = at(x1, y1);
+                        path.quad_to(control, at(x, y));
+                        current = at(x, y);
+                        quad = Some(control);
+                    }
+                    b'T' => {
+                        let [x, y] = parser.numbers()?;
+                        let control = lastQuad.map_or(current, |c| current + (current - c));
+                        path.quad_to(control, at(x, y));
+                        current = at(x, y);
+                        quad = Some(control);
+                    }
+                    _ => {
+                        let [rx, ry, rotation] = parser.numbers()?;
+                        let largeArc = parser.flag()?;
+                        let sweep = parser.flag()?;
+                        parser.skipSeparator();
+                        let [x, y] = parser.numbers()?;
+                        current = at(x, y);
+                        path.arc_to(Vec2::new(rx, ry), rotation.to_radians(), largeArc, sweep, current);
+                    }
+                }
+
+                lastCubic = cubic;
+                lastQuad = quad;
+                first = false;
+                if !parser.nextArguments()? {
+                    break;
+                }
+            }
+        }
+        Ok(path)
+    }
+
+    /// SVG path data with absolute commands, which `from_svg_path` reads back to an equivalent path up to float rounding.
+    /// Arc rotations are written in degrees and may come back slightly off, and a path that starts without
+    /// a move-to gets an explicit `M 0 0`.
+    pub fn to_svg_path(&self) -> String {
+        let mut parts = Vec::new();
+        let point = |p: Point2| format!("{} {}", svgNumber(p.x), svgNumber(p.y));
+        if !matches!(self.commands.first(), None | Some(PathCommand::MoveTo(_))) {
+            parts.push("M 0 0".to_string());
+        }
+
+        for command in &self.commands {
+            parts.push(match *command {
+                PathCommand::MoveTo(p) => format!("M {}", point(p)),
+                PathCommand::LineTo(p) => format!("L {}", point(p)),
+                PathCommand::QuadTo(c, p) => format!("Q {} {}", point(c), point(p)),
+                PathCommand::CubicTo(c1, c2, p) => format!("C {} {} {}", point(c1), point(c2), point(p)),
+                PathCommand::ArcTo { radii, x_rotation, large_arc, sweep, to } => format!(
+                    "A {} {} {} {} {} {}",
+                    svgNumber(radii.x), svgNumber(radii.y), svgNumber(x_rotation.to_degrees()), large_arc as u8, sweep as u8, point(to)
+                ),
+                PathCommand::Close => "Z".to_string()
+            });
+        }
+        parts.join(" ")
+    }
+}
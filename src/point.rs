@@ -1,16 +1,31 @@
-use crate::vector::{Vec2, Vec3};
+use std::ops::{Add, Sub};
+use crate::vector::{Vec2, Vec3, Vec4};
+use crate::approx::ApproxEq;
 
+/// Representation of a point in 2D affine space
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Point2 {
     pub x: f32,
     pub y: f32
 }
 
+/// Representation of a point in 3D affine space
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Point3 {
     pub x: f32,
     pub y: f32,
     pub z: f32
 }
 
+/// Representation of a point in 4D affine space
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Point4 {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+    pub w: f32
+}
+
 impl Point2 {
     pub fn new(x: f32, y: f32) -> Self {
         Point2 { x, y }
@@ -19,6 +34,19 @@ impl Point2 {
     pub fn fromVec(v: Vec2) -> Self {
         Point2 { x: v.x, y: v.y }
     }
+
+    /// Homogeneous coordinates of the point with `z = 1`
+    pub fn to_homogeneous(&self) -> Vec3 {
+        Vec3 { x: self.x, y: self.y, z: 1.0 }
+    }
+
+    /// Point from homogeneous coordinates, or `None` for points at infinity where the last coordinate is exactly zero
+    pub fn from_homogeneous(v: Vec3) -> Option<Self> {
+        if v.z == 0.0 {
+            return None;
+        }
+        Some(Point2 { x: v.x / v.z, y: v.y / v.z })
+    }
 }
 
 impl Point3 {
@@ -29,4 +57,94 @@ impl Point3 {
     pub fn fromVec(v: Vec3) -> Self {
         Point3 { x: v.x, y: v.y, z: v.z }
     }
+
+    /// Homogeneous coordinates of the point with `w = 1`
+    pub fn to_homogeneous(&self) -> Vec4 {
+        Vec4 { x: self.x, y: self.y, z: self.z, w: 1.0 }
+    }
+
+    /// Point from homogeneous coordinates, or `None` for points at infinity where the last coordinate is exactly zero
+    pub fn from_homogeneous(v: Vec4) -> Option<Self> {
+        if v.w == 0.0 {
+            return None;
+        }
+        Some(Point3 { x: v.x / v.w, y: v.y / v.w, z: v.z / v.w })
+    }
 }
+
+impl Point4 {
+    pub fn new(x: f32, y: f32, z: f32, w: f32) -> Self {
+        Point4 { x, y, z, w }
+    }
+
+    pub fn fromVec(v: Vec4) -> Self {
+        Point4 { x: v.x, y: v.y, z: v.z, w: v.w }
+    }
+}
+
+// ##################################################
+// #                 AFFINE ALGEBRA                 #
+// ##################################################
+
+macro_rules! impl_affine {
+    ($point:ident, $vector:ident, $($field:ident),+) => {
+        impl $point {
+            pub const fn origin() -> Self {
+                $point { $($field: 0.0),+ }
+            }
+
+            pub fn distance(&self, other: &Self) -> f32 {
+                (*other - *self).length()
+            }
+
+            pub fn midpoint(&self, other: &Self) -> Self {
+                *self + (*other - *self) * 0.5
+            }
+
+            /// Affine combination `Σ wᵢ·pᵢ` of the weighted points, e.g. barycentric coordinates.
+            /// Returns `None` if there are no points or the weights do not sum to 1.
+            pub fn affine_combination(points: &[(Self, f32)]) -> Option<Self> {
+                let (base, _) = *points.first()?;
+                let mut weightSum = 0.0;
+                let mut offset = $vector::zero();
+                for (p, w) in points {
+                    weightSum += w;
+                    offset = offset + (*p - base) * *w;
+                }
+
+                if !weightSum.approx_eq(&1.0) {
+                    return None;
+                }
+                Some(base + offset)
+            }
+        }
+
+        impl Sub<$point> for $point {
+            type Output = $vector;
+
+            fn sub(self, other: $point) -> $vector {
+                $vector { $($field: self.$field - other.$field),+ }
+            }
+        }
+
+        impl Add<$vector> for $point {
+            type Output = $point;
+
+            fn add(self, other: $vector) -> $point {
+                $point { $($field: self.$field + other.$field),+ }
+            }
+        }
+
+        impl Sub<$vector> for $point {
+            type Output = $point;
+
+            fn sub(self, other: $vector) -> $point {
+                $point { $($field: self.$field - other.$field),+ }
+            }
+        }
+    };
+}
+
+impl_affine!(Point2, Vec2, x, y);
+impl_affine!(Point3, Vec3, x, y, z);
+impl_affine!(Point4, Vec4, x, y, z, w);
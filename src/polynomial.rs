@@ -0,0 +1,140 @@
+use crate::vector::InnerSpace;
+
+// ##################################################
+// #             BERNSTEIN POLYNOMIALS              #
+// ##################################################
+
+pub(crate) fn binomial(n: usize, k: usize) -> f32 {
+    let k = k.min(n - k);
+    let mut result = 1.0_f64;
+    for i in 0..k {
+        result = result * (n - i) as f64 / (i + 1) as f64;
+    }
+    result as f32
+}
+
+/// Evaluates the scalar polynomial with the given Bernstein coefficients at `t`
+pub(crate) fn bernsteinEval(coeffs: &[f32], t: f32) -> f32 {
+    let mut scratch = coeffs.to_vec();
+    for level in (1..scratch.len()).rev() {
+        for i in 0..level {
+            scratch[i] = scratch[i] * (1.0 - t) + scratch[i + 1] * t;
+        }
+    }
+    scratch[0]
+}
+
+/// Splits the Bernstein coefficients at `t` into the coefficients over `[0, t]` and `[t, 1]`
+fn bernsteinSplit(coeffs: &[f32], t: f32) -> (Vec<f32>, Vec<f32>) {
+    let mut scratch = coeffs.to_vec();
+    let mut left = vec![scratch[0]];
+    let mut right = vec![scratch[scratch.len() - 1]];
+    for level in (1..scratch.len()).rev() {
+        for i in 0..level {
+            scratch[i] = scratch[i] * (1.0 - t) + scratch[i + 1] * t;
+        }
+        left.push(scratch[0]);
+        right.push(scratch[level - 1]);
+    }
+    right.reverse();
+    (left, right)
+}
+
+/// Bernstein coefficients of the dot product of two vector valued polynomials given in Bernstein form
+pub(crate) fn bernsteinDot<T: InnerSpace>(a: &[T], b: &[T]) -> Vec<f32> {
+    let (m, n) = (a.len() - 1, b.len() - 1);
+    let mut product = vec![0.0; m + n + 1];
+    for (i, ai) in a.iter().enumerate() {
+        for (j, bj) in b.iter().enumerate() {
+            product[i + j] += binomial(m, i) * binomial(n, j) * ai.dot(bj);
+        }
+    }
+    for (k, c) in product.iter_mut().enumerate() {
+        *c /= binomial(m + n, k);
+    }
+    product
+}
+
+fn signChanges(coeffs: &[f32]) -> usize {
+    let mut changes = 0;
+    let mut previous = 0.0;
+    for &c in coeffs.iter().filter(|c| **c != 0.0) {
+        if previous != 0.0 && (c > 0.0) != (previous > 0.0) {
+            changes += 1;
+        }
+        previous = c;
+    }
+    changes
+}
+
+/// Sign-changing roots of the polynomial with the given Bernstein coefficients in `[0, 1]`, sorted.
+/// Roots are isolated by subdividing until the control polygon crosses zero exactly once
+/// (variation diminishing property), then refined by bisection.
+/// Roots of even multiplicity only show up if they hit an end point or a subdivision point.
+pub(crate) fn bernsteinRoots(coeffs: &[f32]) -> Vec<f32> {
+    let mut roots = Vec::new();
+    if coeffs.len() < 2 {
+        return roots;
+    }
+
+    let scale = coeffs.iter().fold(0.0_f32, |m, c| m.max(c.abs()));
+    if scale == 0.0 {
+        return roots;
+    }
+    let zero = scale * 1e-7;
+
+    if coeffs[0].abs() <= zero {
+        roots.push(0.0);
+    }
+    isolateRoots(coeffs, 0.0, 1.0, zero, 0, &mut roots);
+    if coeffs[coeffs.len() - 1].abs() <= zero {
+        roots.push(1.0);
+    }
+
+    roots.sort_by(f32::total_cmp);
+    roots.dedup_by(|a, b| (*a - *b).abs() < 1e-6);
+    roots
+}
+
+fn isolateRoots(coeffs: &[f32], t0: f32, t1: f32, zero: f32, depth: u32, roots: &mut Vec<f32>) {
+    let changes = signChanges(coeffs);
+    if changes == 0 {
+        return;
+    }
+
+    let first = coeffs[0];
+    let last = coeffs[coeffs.len() - 1];
+    if changes == 1 && first.abs() > zero && last.abs() > zero && (first > 0.0) != (last > 0.0) {
+        roots.push(t0 + (t1 - t0) * bisect(coeffs, first));
+        return;
+    }
+    if depth >= 32 || t1 - t0 < 1e-7 {
+        roots.push(0.5 * (t0 + t1));
+        return;
+    }
+
+    let (left, right) = bernsteinSplit(coeffs, 0.5);
+    let tMid = 0.5 * (t0 + t1);
+    isolateRoots(&left, t0, tMid, zero, depth + 1, roots);
+    if right[0].abs() <= zero {
+        roots.push(tMid);
+    }
+    isolateRoots(&right, tMid, t1, zero, depth + 1, roots);
+}
+
+/// Root of a polynomial that changes sign exactly once over `[0, 1]`
+fn bisect(coeffs: &[f32], first: f32) -> f32 {
+    let (mut lo, mut hi) = (0.0_f32, 1.0_f32);
+    for _ in 0..40 {
+        let mid = 0.5 * (lo + hi);
+        if mid <= lo || mid >= hi {
+            break;
+        }
+        let value = bernsteinEval(coeffs, mid);
+        if value == 0.0 {
+            return mid;
+        }
+        if (value > 0.0) == (first > 0.0) { lo = mid } else { hi = mid }
+    }
+    0.5 * (lo + hi)
+}
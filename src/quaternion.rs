@@ -1,6 +1,7 @@
-use vector::Vec3;
+use crate::vector::Vec3;
 
 /// Representation of a quaternion in scalar/vector form
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Quat {
     pub s: f32,
     pub v: Vec3
@@ -0,0 +1,222 @@
+use crate::point::{Point2, Point3};
+use crate::vector::{Vec2, Vec3, InnerSpace, cross};
+use crate::bezier::{Bezier2, Bezier3, deCasteljau, hodograph};
+use crate::polynomial::bernsteinEval;
+
+// ##################################################
+// #              HOMOGENEOUS EVALUATION            #
+// ##################################################
+
+/// Bernstein coefficients of the derivative of a scalar polynomial
+fn scalarHodograph(values: &[f32]) -> Vec<f32> {
+    let degree = values.len() - 1;
+    if degree == 0 {
+        return vec![0.0];
+    }
+    values.windows(2).map(|w| (w[1] - w[0]) * degree as f32).collect()
+}
+
+/// Numerator `N(t) = Σ Bᵢ(t)·wᵢ·Pᵢ` and denominator `W(t) = Σ Bᵢ(t)·wᵢ` together with their first two derivatives
+struct Homogeneous<T> {
+    numerator: [Vec<T>; 3],
+    denominator: [Vec<f32>; 3]
+}
+
+impl<T: InnerSpace> Homogeneous<T> {
+    fn new(points: &[T], weights: &[f32]) -> Self {
+        let n0: Vec<T> = points.iter().zip(weights).map(|(p, w)| *p * *w).collect();
+        let n1 = hodograph(&n0);
+        let n2 = hodograph(&n1);
+        let w0 = weights.to_vec();
+        let w1 = scalarHodograph(&w0);
+        let w2 = scalarHodograph(&w1);
+        Self { numerator: [n0, n1, n2], denominator: [w0, w1, w2] }
+    }
+
+    fn eval(&self, order: usize, t: f32) -> (T, f32) {
+        (deCasteljau(&self.numerator[order], t), bernsteinEval(&self.denominator[order], t))
+    }
+
+    fn position(&self, t: f32) -> T {
+        let (n, w) = self.eval(0, t);
+        n * (1.0 / w)
+    }
+
+    /// `C' = (N' - W'·C) / W`
+    fn velocity(&self, t: f32) -> T {
+        let (n, w) = self.eval(0, t);
+        let (dn, dw) = self.eval(1, t);
+        let c = n * (1.0 / w);
+        (dn - c * dw) * (1.0 / w)
+    }
+
+    /// `C'' = (N'' - 2·W'·C' - W''·C) / W`
+    fn acceleration(&self, t: f32) -> T {
+        let (n, w) = self.eval(0, t);
+        let (dn, dw) = self.eval(1, t);
+        let (ddn, ddw) = self.eval(2, t);
+        let c = n * (1.0 / w);
+        let dc = (dn - c * dw) * (1.0 / w);
+        (ddn - dc * (2.0 * dw) - c * ddw) * (1.0 / w)
+    }
+}
+
+/// Control points and weights of quadratic arcs of the ellipse `center + u·cos(θ) + v·sin(θ)` for `θ` from `start` to `start + sweep`.
+/// The sweep is split into pieces of at most 90° so every piece is well conditioned.
+fn ellipseArcs<T: InnerSpace>(center: T, u: T, v: T, start: f32, sweep: f32) -> Vec<(Vec<T>, Vec<f32>)> {
+    let pieces = (sweep.abs() / std::f32::consts::FRAC_PI_2).ceil().max(1.0) as usize;
+    let step = sweep / pieces as f32;
+    let halfCos = (0.5 * step).cos();
+    let at = |angle: f32, scale: f32| center + (u * angle.cos() + v * angle.sin()) * scale;
+
+    (0..pieces).map(|i| {
+        let a0 = start + step * i as f32;
+        let points = vec![at(a0, 1.0), at(a0 + 0.5 * step, 1.0 / halfCos), at(a0 + step, 1.0)];
+        (points, vec![1.0, halfCos, 1.0])
+    }).collect()
+}
+
+// ##################################################
+// #                 SHARED METHODS                 #
+// ##################################################
+
+macro_rules! impl_rational_bezier {
+    ($name:ident, $bezier:ident, $point:ident, $vector:ident) => {
+        impl $name {
+            /// Curve through the given control points with one weight per point.
+            /// Panics if there are no points, the counts differ or a weight is not positive.
+            pub fn new(points: Vec<$vector>, weights: Vec<f32>) -> Self {
+                assert!(!points.is_empty(), "A Bézier curve needs at least one control point!");
+                assert_eq!(points.len(), weights.len(), "Every control point needs exactly one weight!");
+                assert!(weights.iter().all(|w| *w > 0.0), "Weights of a rational Bézier curve must be positive!");
+                Self { points, weights }
+            }
+
+            /// The polynomial curve as a rational curve with unit weights
+            pub fn from_bezier(curve: &$bezier) -> Self {
+                Self::new(curve.points().to_vec(), vec![1.0; curve.points().len()])
+            }
+
+            /// Quadratic conic section from `p0` to `p2` with the middle control point `p1` weighted by `w`.
+            /// It is an ellipse for `w < 1`, a parabola for `w = 1` and a hyperbola for `w > 1`.
+            pub fn conic(p0: $point, p1: $point, p2: $point, w: f32) -> Self {
+                Self::new(vec![$vector::fromPoint(p0), $vector::fromPoint(p1), $vector::fromPoint(p2)], vec![1.0, w, 1.0])
+            }
+
+            pub fn points(&self) -> &[$vector] {
+                &self.points
+            }
+
+            pub fn weights(&self) -> &[f32] {
+                &self.weights
+            }
+
+            pub fn degree(&self) -> usize {
+                self.points.len() - 1
+            }
+
+            /// Evaluates the curve in homogeneous space and projects the result back
+            pub fn eval(&self, t: f32) -> $point {
+                $point::fromVec(Homogeneous::new(&self.points, &self.weights).position(t))
+            }
+
+            /// First derivative with respect to `t`
+            pub fn velocity(&self, t: f32) -> $vector {
+                Homogeneous::new(&self.points, &self.weights).velocity(t)
+            }
+
+            /// Second derivative with respect to `t`
+            pub fn acceleration(&self, t: f32) -> $vector {
+                Homogeneous::new(&self.points, &self.weights).acceleration(t)
+            }
+
+            /// Unit tangent at `t`, or zero where the curve is degenerate
+            pub fn tangent(&self, t: f32) -> $vector {
+                self.velocity(t).normalize_or_zero()
+            }
+        }
+    };
+}
+
+// ##################################################
+// #            RATIONAL BEZIER CURVE 2D            #
+// ##################################################
+
+/// Rational Bézier curve of arbitrary degree in R², which can represent conic sections exactly
+#[derive(Debug, Clone, PartialEq)]
+pub struct RationalBezier2 {
+    points: Vec<Vec2>,
+    weights: Vec<f32>
+}
+
+impl_rational_bezier!(RationalBezier2, Bezier2, Point2, Vec2);
+
+impl RationalBezier2 {
+    /// Unit normal at `t`, the tangent rotated counterclockwise by 90°
+    pub fn normal(&self, t: f32) -> Vec2 {
+        self.tangent(t).perp()
+    }
+
+    /// Exact arc of the circle around `center`, from `start_angle` sweeping counterclockwise by `sweep` radians.
+    /// Returns one quadratic piece per started quarter turn.
+    pub fn circular_arc(center: Point2, radius: f32, start_angle: f32, sweep: f32) -> Vec<Self> {
+        Self::ellipse_arc(center, radius, radius, 0.0, start_angle, sweep)
+    }
+
+    /// Exact full circle made of four quadratic pieces
+    pub fn circle(center: Point2, radius: f32) -> Vec<Self> {
+        Self::circular_arc(center, radius, 0.0, std::f32::consts::TAU)
+    }
+
+    /// Exact arc of the ellipse with radii `rx` and `ry` whose x axis is rotated by `rotation`.
+    /// Angles are measured in the ellipse's own frame before the rotation.
+    pub fn ellipse_arc(center: Point2, rx: f32, ry: f32, rotation: f32, start_angle: f32, sweep: f32) -> Vec<Self> {
+        let (sin, cos) = rotation.sin_cos();
+        let u = Vec2::new(cos, sin) * rx;
+        let v = Vec2::new(-sin, cos) * ry;
+        ellipseArcs(Vec2::fromPoint(center), u, v, start_angle, sweep).into_iter()
+            .map(|(points, weights)| Self::new(points, weights))
+            .collect()
+    }
+
+    /// Exact full ellipse made of four quadratic pieces
+    pub fn ellipse(center: Point2, rx: f32, ry: f32, rotation: f32) -> Vec<Self> {
+        Self::ellipse_arc(center, rx, ry, rotation, 0.0, std::f32::consts::TAU)
+    }
+}
+
+// ##################################################
+// #            RATIONAL BEZIER CURVE 3D            #
+// ##################################################
+
+/// Rational Bézier curve of arbitrary degree in R³, which can represent conic sections exactly
+#[derive(Debug, Clone, PartialEq)]
+pub struct RationalBezier3 {
+    points: Vec<Vec3>,
+    weights: Vec<f32>
+}
+
+impl_rational_bezier!(RationalBezier3, Bezier3, Point3, Vec3);
+
+impl RationalBezier3 {
+    /// Unit principal normal at `t`, or zero where the curve is locally straight
+    pub fn normal(&self, t: f32) -> Vec3 {
+        let velocity = self.velocity(t);
+        cross(cross(velocity, self.acceleration(t)), velocity).normalize_or_zero()
+    }
+
+    /// Exact arc of the circle around `center` in the plane with the given `normal`,
+    /// sweeping counterclockwise around the normal by `sweep` radians from `start_angle`.
+    /// Angles are measured from an arbitrary but fixed direction in the plane.
+    pub fn circular_arc(center: Point3, normal: Vec3, radius: f32, start_angle: f32, sweep: f32) -> Vec<Self> {
+        let (u, v) = normal.normalize().any_orthonormal_pair();
+        Self::ellipse_arc(center, u * radius, v * radius, start_angle, sweep)
+    }
+
+    /// Exact arc of the ellipse `center + u·cos(θ) + v·sin(θ)`, where the axes `u` and `v` carry the radii
+    pub fn ellipse_arc(center: Point3, u: Vec3, v: Vec3, start_angle: f32, sweep: f32) -> Vec<Self> {
+        ellipseArcs(Vec3::fromPoint(center), u, v, start_angle, sweep).into_iter()
+            .map(|(points, weights)| Self::new(points, weights))
+            .collect()
+    }
+}
@@ -0,0 +1,493 @@
+use std::f32::consts::PI;
+use crate::bezier::Bezier2;
+use crate::mesh::Mesh;
+use crate::path::{Path2, Subpath};
+use crate::point::Point2;
+use crate::vector::{Vec2, Vec3, InnerSpace};
+
+/// Below this squared length a vector counts as zero
+const DegenerateEpsilon: f32 = 1e-12;
+
+/// Recursion limit for the offset approximation, which bounds the output at 2^12 cubics per curve
+const MaxOffsetDepth: usize = 12;
+
+/// Most repetitions of the dash pattern along one segment before the stroke is drawn solid instead
+const MaxDashPeriods: f32 = 1e5;
+
+// ##################################################
+// #                OFFSET CURVES                   #
+// ##################################################
+
+/// Unit tangents at the start and end of the curve, taken from the control polygon so they exist
+/// even where the velocity vanishes. Zero if all control points coincide.
+fn endTangents(points: &[Vec2]) -> (Vec2, Vec2) {
+    let first = points[0];
+    let last = points[points.len() - 1];
+    let start = points.iter().map(|p| *p - first).find(|d| d.length_squared() > DegenerateEpsilon);
+    let end = points.iter().rev().map(|p| last - *p).find(|d| d.length_squared() > DegenerateEpsilon);
+    (start.map_or(Vec2::zero(), |d| d.normalize()), end.map_or(Vec2::zero(), |d| d.normalize()))
+}
+
+fn isDegenerate(curve: &Bezier2) -> bool {
+    endTangents(curve.points()).0 == Vec2::zero()
+}
+
+/// Left unit normal at `t`, falling back to the control polygon at the ends and to a nearby sample elsewhere
+fn offsetNormal(curve: &Bezier2, t: f32) -> Vec2 {
+    let (start, end) = endTangents(curve.points());
+    match curve.velocity(t).try_normalize() {
+        Some(tangent) => tangent.perp(),
+        None if t <= 0.0 => start.perp(),
+        None if t >= 1.0 => end.perp(),
+        None => curve.velocity(if t < 0.5 { t + 1e-3 } else { t - 1e-3 }).normalize_or_zero().perp()
+    }
+}
+
+/// Signed curvature at `t`, positive when the curve turns left. Zero where the velocity vanishes.
+fn signedCurvature(curve: &Bezier2, t: f32) -> f32 {
+    let velocity = curve.velocity(t);
+    let speed = velocity.length();
+    if speed * speed < DegenerateEpsilon {
+        return 0.0;
+    }
+    let acceleration = curve.derivative().velocity(t);
+    velocity.perp_dot(&acceleration) / (speed * speed * speed)
+}
+
+/// Approximates the offset of `curve` over `[t0, t1]` with a cubic Hermite curve, splitting until it is within `tolerance`
+fn offsetRange(curve: &Bezier2, distance: f32, t0: f32, t1: f32, tolerance: f32, depth: usize, out: &mut Vec<Bezier2>) {
+    let offsetPoint = |t: f32| Vec2::fromPoint(curve.eval(t)) + offsetNormal(curve, t) * distance;
+    // The offset curve moves with velocity r'(t)·(1 - dκ), scaled here to the local parameter of the piece
+    let offsetVelocity = |t: f32| curve.velocity(t) * ((1.0 - distance * signedCurvature(curve, t)) * (t1 - t0));
+
+    let (p0, p3) = (offsetPoint(t0), offsetPoint(t1));
+    let piece = Bezier2::new(vec![p0, p0 + offsetVelocity(t0) / 3.0, p3 - offsetVelocity(t1) / 3.0, p3]);
+
+    let error = [0.125, 0.25, 0.375, 0.5, 0.625, 0.75, 0.875].iter().map(|s| {
+        (Vec2::fromPoint(piece.eval(*s)) - offsetPoint(t0 + (t1 - t0) * s)).length()
+    }).fold(0.0, f32::max);
+
+    if error <= tolerance || depth >= MaxOffsetDepth {
+        out.push(piece);
+        return;
+    }
+    let mid = 0.5 * (t0 + t1);
+    offsetRange(curve, distance, t0, mid, tolerance, depth + 1, out);
+    offsetRange(curve, distance, mid, t1, tolerance, depth + 1, out);
+}
+
+impl Bezier2 {
+    /// Curve at constant `distance` to the left of this one, to the right for negative distances.
+    /// The offset of a Bézier curve is not a Bézier curve in general, so it is approximated by cubics that
+    /// stay within `tolerance` of it. Straight lines stay lines of degree 1.
+    pub fn offset(&self, distance: f32, tolerance: f32) -> Vec<Bezier2> {
+        if self.degree() <= 1 || isDegenerate(self) {
+            let normal = offsetNormal(self, 0.0);
+            return vec![Self::new(self.points().iter().map(|p| *p + normal * distance).collect())];
+        }
+        let mut pieces = Vec::new();
+        offsetRange(self, distance, 0.0, 1.0, tolerance.max(1e-6), 0, &mut pieces);
+        pieces
+    }
+}
+
+// ##################################################
+// #                 STROKE STYLE                   #
+// ##################################################
+
+/// Shape of the outer corner where two segments of a stroke meet
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LineJoin {
+    /// Sharp corner, cut off like `Bevel` when longer than `miter_limit` times the stroke width
+    Miter,
+    Round,
+    Bevel
+}
+
+/// Shape of the open ends of a stroke
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LineCap {
+    /// Ends exactly at the end point
+    Butt,
+    Round,
+    /// Extends past the end point by half the stroke width
+    Square
+}
+
+/// Stroke parameters following the SVG stroke properties
+#[derive(Debug, Clone, PartialEq)]
+pub struct StrokeStyle {
+    pub width: f32,
+    pub join: LineJoin,
+    pub miter_limit: f32,
+    pub cap: LineCap,
+    /// Alternating lengths of dashes and gaps, repeated twice if odd. Empty for a solid stroke.
+    pub dashes: Vec<f32>,
+    /// Distance into the dash pattern at the start of every subpath
+    pub dash_offset: f32,
+    /// Maximum distance of the generated outline from the exact one
+    pub tolerance: f32
+}
+
+impl Default for StrokeStyle {
+    fn default() -> Self {
+        Self { width: 1.0, join: LineJoin::Miter, miter_limit: 4.0, cap: LineCap::Butt, dashes: Vec::new(), dash_offset: 0.0, tolerance: 0.01 }
+    }
+}
+
+// ##################################################
+// #                    DASHING                     #
+// ##################################################
+
+/// Part of a path to stroke, either a run of segments or a zero-length dash that only its caps draw
+enum StrokePart {
+    Segments(Subpath),
+    Dot { at: Vec2, direction: Vec2 }
+}
+
+/// Zero-length dash at `t` on the curve, facing along the curve
+fn dotAt(curve: &Bezier2, t: f32) -> StrokePart {
+    StrokePart::Dot { at: Vec2::fromPoint(curve.eval(t)), direction: -offsetNormal(curve, t).perp() }
+}
+
+/// Splits the subpaths into open dashes. Patterns with negative lengths or a zero sum draw solid, as in SVG.
+/// So do patterns too fine for the segments, which would repeat more than `MaxDashPeriods` times on one or step below the float precision.
+fn dashSubpaths(subpaths: Vec<Subpath>, dashes: &[f32], offset: f32) -> Vec<StrokePart> {
+    let solid = |subpaths: Vec<Subpath>| subpaths.into_iter().map(StrokePart::Segments).collect();
+    let total: f32 = dashes.iter().sum();
+    if dashes.is_empty() || total <= 0.0 || dashes.iter().any(|d| *d < 0.0) {
+        return solid(subpaths);
+    }
+    let pattern: Vec<f32> = if dashes.len() % 2 == 1 { dashes.iter().chain(dashes).copied().collect() } else { dashes.to_vec() };
+    let period = if dashes.len() % 2 == 1 { 2.0 * total } else { total };
+
+    let mut dashed = Vec::new();
+    for subpath in &subpaths {
+        // Every subpath starts over at the dash offset
+        let mut index = 0;
+        let mut phase = offset.rem_euclid(period);
+        while phase > pattern[index] || (phase == pattern[index] && index % 2 == 1) {
+            phase -= pattern[index];
+            index = (index + 1) % pattern.len();
+        }
+        let mut remaining = pattern[index] - phase;
+
+        let mut current = Vec::new();
+        let mut last = None;
+        for segment in &subpath.segments {
+            let length = segment.arc_length();
+            if length / period > MaxDashPeriods {
+                return solid(subpaths);
+            }
+            let mut position = 0.0;
+            while position < length {
+                let step = remaining.min(length - position);
+                if step > 0.0 && position + step == position {
+                    return solid(subpaths);
+                }
+                if index % 2 == 0 && step > 0.0 {
+                    current.push(segment.subsegment(segment.t_at_length(position), segment.t_at_length(position + step)));
+                } else if index % 2 == 0 && pattern[index] == 0.0 {
+                    dashed.push(dotAt(segment, segment.t_at_length(position)));
+                }
+                position += step;
+                remaining -= step;
+                if remaining <= 0.0 {
+                    if !current.is_empty() {
+                        dashed.push(StrokePart::Segments(Subpath { segments: std::mem::take(&mut current), closed: false }));
+                    }
+                    index = (index + 1) % pattern.len();
+                    remaining = pattern[index];
+                }
+            }
+            if length > 0.0 {
+                last = Some(segment);
+            }
+        }
+        if !current.is_empty() {
+            dashed.push(StrokePart::Segments(Subpath { segments: current, closed: false }));
+        }
+        // A zero-length dash at the very end of an open subpath, where a closed one would repeat its first dash
+        if let Some(last) = last.filter(|_| !subpath.closed && index % 2 == 0 && pattern[index] == 0.0) {
+            dashed.push(dotAt(last, 1.0));
+        }
+    }
+    dashed
+}
+
+// ##################################################
+// #                STROKE OUTLINE                  #
+// ##################################################
+
+/// Connects the left offsets of two segments meeting at `vertex` with incoming direction `a` and outgoing direction `b`.
+/// The inner side goes through the vertex itself, which keeps the outline fillable with the nonzero rule.
+fn pushJoin(outline: &mut Path2, vertex: Vec2, a: Vec2, b: Vec2, halfWidth: f32, style: &StrokeStyle) {
+    let next = Point2::fromVec(vertex + b.perp() * halfWidth);
+    let turn = a.perp_dot(&b);
+    if turn.abs() < 1e-6 && a.dot(&b) > 0.0 {
+        outline.line_to(next);
+        return;
+    }
+    if turn > 0.0 {
+        outline.line_to(Point2::fromVec(vertex)).line_to(next);
+        return;
+    }
+
+    match style.join {
+        LineJoin::Miter => {
+            let bisector = a.perp() + b.perp();
+            let lengthSquared = bisector.length_squared();
+            if lengthSquared > DegenerateEpsilon && 2.0 / lengthSquared.sqrt() <= style.miter_limit {
+                outline.line_to(Point2::fromVec(vertex + bisector * (2.0 * halfWidth / lengthSquared)));
+            }
+            outline.line_to(next);
+        }
+        LineJoin::Round => { outline.arc_to(Vec2::new(halfWidth, halfWidth), 0.0, false, false, next); }
+        LineJoin::Bevel => { outline.line_to(next); }
+    }
+}
+
+/// Closes the end of a stroke at `end` with direction `tangent`, from the left offset to the right offset
+fn pushCap(outline: &mut Path2, end: Vec2, tangent: Vec2, halfWidth: f32, cap: LineCap) {
+    let side = tangent.perp() * halfWidth;
+    let right = Point2::fromVec(end - side);
+    match cap {
+        LineCap::Butt => { outline.line_to(right); }
+        LineCap::Round => { outline.arc_to(Vec2::new(halfWidth, halfWidth), 0.0, false, false, right); }
+        LineCap::Square => {
+            let extension = tangent * halfWidth;
+            outline.line_to(Point2::fromVec(end + side + extension)).line_to(Point2::fromVec(end - side + extension)).line_to(right);
+        }
+    }
+}
+
+/// Appends the left offset of the segments, starting at the offset of the first start point
+fn pushOffsetSide(outline: &mut Path2, segments: &[Bezier2], closed: bool, halfWidth: f32, style: &StrokeStyle) {
+    for (i, segment) in segments.iter().enumerate() {
+        for piece in segment.offset(halfWidth, style.tolerance) {
+            match *piece.points() {
+                [_, p] => { outline.line_to(Point2::fromVec(p)); }
+                [_, c1, c2, p] => { outline.cubic_to(Point2::fromVec(c1), Point2::fromVec(c2), Point2::fromVec(p)); }
+                _ => unreachable!()
+            }
+        }
+
+        let next = if i + 1 < segments.len() { segments.get(i + 1) } else if closed { segments.first() } else { None };
+        if let Some(next) = next {
+            let vertex = segment.points()[segment.degree()];
+            pushJoin(outline, vertex, endTangents(segment.points()).1, endTangents(next.points()).0, halfWidth, style);
+        }
+    }
+}
+
+impl Path2 {
+    /// Parts to stroke, after dashing and without degenerate segments
+    fn strokeParts(&self, style: &StrokeStyle) -> Vec<StrokePart> {
+        dashSubpaths(self.subpaths(), &style.dashes, style.dash_offset).into_iter().filter_map(|part| match part {
+            StrokePart::Segments(subpath) => {
+                let segments: Vec<Bezier2> = subpath.segments.into_iter().filter(|s| !isDegenerate(s)).collect();
+                (!segments.is_empty()).then_some(StrokePart::Segments(Subpath { segments, closed: subpath.closed }))
+            }
+            dot => Some(dot)
+        }).collect()
+    }
+
+    /// Outline of the stroke as a path to be filled with the nonzero winding rule.
+    /// Open subpaths and dashes become one closed contour each, closed subpaths become an outer and an inner contour.
+    /// Curved parts are offset within `style.tolerance`.
+    pub fn stroke(&self, style: &StrokeStyle) -> Path2 {
+        let halfWidth = 0.5 * style.width;
+        let mut outline = Path2::new();
+        for part in self.strokeParts(style) {
+            let subpath = match part {
+                StrokePart::Segments(subpath) => subpath,
+                StrokePart::Dot { at, direction } => {
+                    // Two caps back to back, which leave nothing for butt caps
+                    if style.cap != LineCap::Butt {
+                        outline.move_to(Point2::fromVec(at + direction.perp() * halfWidth));
+                        pushCap(&mut outline, at, direction, halfWidth, style.cap);
+                        pushCap(&mut outline, at, -direction, halfWidth, style.cap);
+                        outline.close();
+                    }
+                    continue;
+                }
+            };
+            let forward = subpath.segments;
+            let backward: Vec<Bezier2> = forward.iter().rev().map(|s| s.reverse()).collect();
+
+            for (i, side) in [&forward, &backward].into_iter().enumerate() {
+                let (start, startTangent) = (side[0].points()[0], endTangents(side[0].points()).0);
+                if subpath.closed || i == 0 {
+                    outline.move_to(Point2::fromVec(start + startTangent.perp() * halfWidth));
+                }
+                pushOffsetSide(&mut outline, side, subpath.closed, halfWidth, style);
+
+                let last = &side[side.len() - 1];
+                if subpath.closed {
+                    outline.close();
+                } else {
+                    pushCap(&mut outline, last.points()[last.degree()], endTangents(last.points()).1, halfWidth, style.cap);
+                }
+            }
+            if !subpath.closed {
+                outline.close();
+            }
+        }
+        outline
+    }
+}
+
+// ##################################################
+// #               STROKE TRIANGLES                 #
+// ##################################################
+
+/// Collects stroke triangles in the plane `z = 0`, facing `+z`
+struct StrokeMesh {
+    mesh: Mesh,
+    halfWidth: f32
+}
+
+impl StrokeMesh {
+    /// Pushes a triangle with the given positions and UVs, flipped if necessary so it is counterclockwise
+    fn triangle(&mut self, corners: [(Vec2, Vec2); 3]) {
+        let [(a, _), (b, _), (c, _)] = corners;
+        let area = (b - a).perp_dot(&(c - a));
+        if area.abs() < DegenerateEpsilon {
+            return;
+        }
+        let order = if area > 0.0 { [0, 1, 2] } else { [0, 2, 1] };
+        for k in order {
+            let (p, uv) = corners[k];
+            let index = self.mesh.push_vertex(Vec3::new(p.x, p.y, 0.0), Vec3::new(0.0, 0.0, 1.0), uv);
+            self.mesh.indices.push(index);
+        }
+    }
+
+    /// Fan of triangles around `center` from direction `from` turning by `angle`, with `rimV` giving the UV `v` for a rim direction
+    fn fan(&mut self, center: Vec2, from: Vec2, angle: f32, u: f32, tolerance: f32, rimV: impl Fn(Vec2) -> f32) {
+        let h = self.halfWidth;
+        let maxStep = 2.0 * (1.0 - (tolerance / h).min(1.0)).acos();
+        let steps = (angle.abs() / maxStep.max(1e-3)).ceil().max(1.0) as usize;
+        let rotate = |v: Vec2, angle: f32| {
+            let (sin, cos) = angle.sin_cos();
+            Vec2::new(cos * v.x - sin * v.y, sin * v.x + cos * v.y)
+        };
+        for i in 0..steps {
+            let d0 = rotate(from, angle * i as f32 / steps as f32);
+            let d1 = rotate(from, angle * (i + 1) as f32 / steps as f32);
+            self.triangle([(center, Vec2::new(u, 0.5)), (center + d0 * h, Vec2::new(u, rimV(d0))), (center + d1 * h, Vec2::new(u, rimV(d1)))]);
+        }
+    }
+
+    fn join(&mut self, vertex: Vec2, a: Vec2, b: Vec2, u: f32, style: &StrokeStyle) {
+        let turn = a.perp_dot(&b);
+        if turn.abs() < 1e-6 && a.dot(&b) > 0.0 {
+            return;
+        }
+        // The gap to fill is on the right side of a left turn and vice versa
+        let side = if turn > 0.0 { -1.0 } else { 1.0 };
+        let edgeV = if side > 0.0 { 0.0 } else { 1.0 };
+        let (n0, n1) = (a.perp() * side, b.perp() * side);
+        let h = self.halfWidth;
+        let center = (vertex, Vec2::new(u, 0.5));
+        let outer0 = (vertex + n0 * h, Vec2::new(u, edgeV));
+        let outer1 = (vertex + n1 * h, Vec2::new(u, edgeV));
+
+        match style.join {
+            LineJoin::Miter => {
+                let bisector = n0 + n1;
+                let lengthSquared = bisector.length_squared();
+                if lengthSquared > DegenerateEpsilon && 2.0 / lengthSquared.sqrt() <= style.miter_limit {
+                    let tip = (vertex + bisector * (2.0 * h / lengthSquared), Vec2::new(u, edgeV));
+                    self.triangle([center, outer0, tip]);
+                    self.triangle([center, tip, outer1]);
+                } else {
+                    self.triangle([center, outer0, outer1]);
+                }
+            }
+            LineJoin::Round => self.fan(vertex, n0, n0.signed_angle(&n1), u, style.tolerance, |_| edgeV),
+            LineJoin::Bevel => self.triangle([center, outer0, outer1])
+        }
+    }
+
+    /// Cap at `end` pointing along `outward`, where `left` is the unit normal on the left of the stroke direction
+    fn cap(&mut self, end: Vec2, outward: Vec2, left: Vec2, u: f32, style: &StrokeStyle) {
+        let h = self.halfWidth;
+        match style.cap {
+            LineCap::Butt => {}
+            LineCap::Round => self.fan(end, outward.perp(), -PI, u, style.tolerance, |d| 0.5 - 0.5 * d.dot(&left)),
+            LineCap::Square => {
+                let (leftEdge, rightEdge) = ((end + left * h, Vec2::new(u, 0.0)), (end - left * h, Vec2::new(u, 1.0)));
+                let extension = outward * h;
+                let (leftTip, rightTip) = ((leftEdge.0 + extension, leftEdge.1), (rightEdge.0 + extension, rightEdge.1));
+                self.triangle([leftEdge, rightEdge, rightTip]);
+                self.triangle([leftEdge, rightTip, leftTip]);
+            }
+        }
+    }
+
+    /// Triangulates a flattened subpath. `u` in the UVs is the distance along the subpath and `v` runs from 0 on the left edge to 1 on the right.
+    fn polyline(&mut self, points: &[Vec2], closed: bool, style: &StrokeStyle) {
+        let h = self.halfWidth;
+        let count = points.len();
+        let segmentCount = if closed { count } else { count - 1 };
+        let direction = |i: usize| (points[(i + 1) % count] - points[i]).normalize();
+
+        let mut distance = 0.0;
+        let mut distances = Vec::with_capacity(count + 1);
+        for i in 0..segmentCount {
+            let (a, b) = (points[i], points[(i + 1) % count]);
+            let normal = direction(i).perp() * h;
+            let length = (b - a).length();
+            distances.push(distance);
+            self.triangle([(a - normal, Vec2::new(distance, 1.0)), (b - normal, Vec2::new(distance + length, 1.0)), (b + normal, Vec2::new(distance + length, 0.0))]);
+            self.triangle([(a - normal, Vec2::new(distance, 1.0)), (b + normal, Vec2::new(distance + length, 0.0)), (a + normal, Vec2::new(distance, 0.0))]);
+            distance += length;
+        }
+        distances.push(distance);
+
+        let joins = if closed { 0..count } else { 1..count - 1 };
+        for i in joins {
+            let previous = (i + count - 1) % count;
+            self.join(points[i], direction(previous), direction(i), distances[i], style);
+        }
+        if !closed {
+            self.cap(points[0], -direction(0), direction(0).perp(), 0.0, style);
+            self.cap(points[count - 1], direction(count - 2), direction(count - 2).perp(), distance, style);
+        }
+    }
+}
+
+impl Path2 {
+    /// Triangles covering the stroke, for rendering without a path filler. Curves are flattened within `style.tolerance`.
+    /// Joins and caps overlap the segments, so the triangles should be drawn opaque or with a stencil.
+    pub fn stroke_triangles(&self, style: &StrokeStyle) -> Mesh {
+        let mut stroke = StrokeMesh { mesh: Mesh::new(), halfWidth: 0.5 * style.width };
+        for part in self.strokeParts(style) {
+            let subpath = match part {
+                StrokePart::Segments(subpath) => subpath,
+                StrokePart::Dot { at, direction } => {
+                    stroke.cap(at, direction, direction.perp(), 0.0, style);
+                    stroke.cap(at, -direction, direction.perp(), 0.0, style);
+                    continue;
+                }
+            };
+            let mut points: Vec<Vec2> = Vec::new();
+            for segment in &subpath.segments {
+                for p in segment.flatten(style.tolerance) {
+                    let p = Vec2::fromPoint(p);
+                    if points.last().is_none_or(|last| (p - *last).length_squared() > DegenerateEpsilon) {
+                        points.push(p);
+                    }
+                }
+            }
+            if subpath.closed && points.len() > 1 && (points[0] - points[points.len() - 1]).length_squared() <= DegenerateEpsilon {
+                points.pop();
+            }
+            if points.len() >= 2 {
+                stroke.polyline(&points, subpath.closed && points.len() >= 3, style);
+            }
+        }
+        stroke.mesh
+    }
+}
@@ -0,0 +1,173 @@
+use crate::bounds::Aabb2;
+use crate::frame::Curve3;
+use crate::mesh::Mesh;
+use crate::point::Point2;
+use crate::utilities::CubicSpline;
+use crate::vector::{Vec2, Vec3, InnerSpace, cross};
+
+// ##################################################
+// #                SWEEP OPTIONS                   #
+// ##################################################
+
+/// Settings for `sweep`. The twist and scale curves map the normalized path parameter `s ∈ [0, 1]`
+/// to an angle in radians and a scale factor applied to the profile, e.g. `CubicSpline::natural(&[(0.0, 0.0), (1.0, TAU)])`
+/// for one full linear turn.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SweepOptions {
+    /// Number of segments along the path
+    pub path_segments: usize,
+    /// Whether the last profile point connects back to the first, like the cross-section of a pipe
+    pub closed_profile: bool,
+    /// Whether closed profiles get flat caps at both ends of the path
+    pub caps: bool,
+    pub twist: Option<CubicSpline>,
+    pub scale: Option<CubicSpline>
+}
+
+impl Default for SweepOptions {
+    fn default() -> Self {
+        Self { path_segments: 32, closed_profile: true, caps: false, twist: None, scale: None }
+    }
+}
+
+// ##################################################
+// #                 TRIANGULATION                  #
+// ##################################################
+
+fn cross2(a: Vec2, b: Vec2) -> f32 {
+    a.x * b.y - a.y * b.x
+}
+
+/// Triangulates a simple polygon by ear clipping. The triangles are counterclockwise whatever the orientation of the polygon.
+fn triangulatePolygon(points: &[Point2]) -> Vec<[usize; 3]> {
+    let n = points.len();
+    let area: f32 = (0..n).map(|i| cross2(Vec2::fromPoint(points[i]), Vec2::fromPoint(points[(i + 1) % n]))).sum();
+    let orientation = if area < 0.0 { -1.0 } else { 1.0 };
+    let corner = |a: usize, b: usize, c: usize| cross2(points[b] - points[a], points[c] - points[b]) * orientation;
+
+    let mut remaining: Vec<usize> = (0..n).collect();
+    let mut triangles = Vec::with_capacity(n.saturating_sub(2));
+    while remaining.len() > 3 {
+        let m = remaining.len();
+        let isEar = |k: usize| {
+            let (a, b, c) = (remaining[(k + m - 1) % m], remaining[k], remaining[(k + 1) % m]);
+            corner(a, b, c) > 0.0 && remaining.iter().all(|&p| {
+                p == a || p == b || p == c
+                    || corner(a, b, p) < 0.0 || corner(b, c, p) < 0.0 || corner(c, a, p) < 0.0
+            })
+        };
+        // Degenerate or self-intersecting polygons may have no ear left, then clipping any corner keeps going
+        let k = (0..m).find(|k| isEar(*k)).unwrap_or(0);
+        triangles.push([remaining[(k + m - 1) % m], remaining[k], remaining[(k + 1) % m]]);
+        remaining.remove(k);
+    }
+    if remaining.len() == 3 {
+        triangles.push([remaining[0], remaining[1], remaining[2]]);
+    }
+
+    if orientation < 0.0 {
+        for triangle in &mut triangles {
+            triangle.swap(1, 2);
+        }
+    }
+    triangles
+}
+
+// ##################################################
+// #                     SWEEP                      #
+// ##################################################
+
+/// Average of the unit directions towards and away from the neighbours, skipping coincident points.
+/// Duplicating a point therefore gives a hard edge.
+fn gridDirection(previous: Option<Vec3>, current: Vec3, next: Option<Vec3>) -> Vec3 {
+    let incoming = previous.map_or(Vec3::zero(), |p| (current - p).normalize_or_zero());
+    let outgoing = next.map_or(Vec3::zero(), |n| (n - current).normalize_or_zero());
+    incoming + outgoing
+}
+
+/// Sweeps the 2D `profile` along `path`, which places its x axis along the normal and its y axis along the binormal
+/// of rotation-minimizing frames. The surface faces the right-hand side of the profile direction,
+/// so counterclockwise closed profiles face outwards.
+/// UVs run along the profile in `u` and along the path in `v`, both proportional to length and normalized to `[0, 1]`.
+/// Panics if the profile has fewer than two points.
+pub fn sweep<C: Curve3>(profile: &[Point2], path: &C, options: &SweepOptions) -> Mesh {
+    assert!(profile.len() >= 2, "A sweep profile needs at least two points!");
+    let segments = options.path_segments.max(1);
+    let closed = options.closed_profile && profile.len() >= 3;
+    let (start, end) = path.domain();
+
+    let ts: Vec<f32> = (0..=segments).map(|i| start + (end - start) * i as f32 / segments as f32).collect();
+    let frames = path.rotation_minimizing_frames(&ts);
+
+    // Ring of swept profile points for every frame
+    let rings: Vec<Vec<Vec3>> = frames.iter().enumerate().map(|(i, frame)| {
+        let s = i as f32 / segments as f32;
+        let angle = options.twist.as_ref().map_or(0.0, |twist| twist.eval(s));
+        let scale = options.scale.as_ref().map_or(1.0, |scale| scale.eval(s));
+        let (sin, cos) = angle.sin_cos();
+        profile.iter().map(|p| {
+            let (x, y) = (cos * p.x - sin * p.y, sin * p.x + cos * p.y);
+            Vec3::fromPoint(frame.to_world(x * scale, y * scale))
+        }).collect()
+    }).collect();
+
+    let n = profile.len();
+    let neighbour = |j: isize| -> Option<usize> {
+        if closed { Some(j.rem_euclid(n as isize) as usize) } else if (0..n as isize).contains(&j) { Some(j as usize) } else { None }
+    };
+
+    let mut profileLengths = vec![0.0];
+    for j in 1..=n {
+        let Some(k) = neighbour(j as isize) else { break };
+        profileLengths.push(profileLengths[j - 1] + profile[j - 1].distance(&profile[k]));
+    }
+    let mut pathLengths = vec![0.0];
+    for i in 1..=segments {
+        pathLengths.push(pathLengths[i - 1] + frames[i - 1].origin.distance(&frames[i].origin));
+    }
+    let normalized = |lengths: &[f32], k: usize| {
+        let total = lengths[lengths.len() - 1];
+        if total > 0.0 { lengths[k] / total } else { 0.0 }
+    };
+
+    // Rows follow the profile and columns follow the path, closed profiles repeat their first point for the UV seam
+    let mut mesh = Mesh::new();
+    let rows = profileLengths.len();
+    for j in 0..rows {
+        let k = j % n;
+        for i in 0..=segments {
+            let position = rings[i][k];
+            let alongProfile = gridDirection(
+                neighbour(k as isize - 1).map(|p| rings[i][p]), position, neighbour(k as isize + 1).map(|p| rings[i][p])
+            );
+            let alongPath = gridDirection(i.checked_sub(1).map(|p| rings[p][k]), position, rings.get(i + 1).map(|r| r[k]));
+            let normal = cross(alongProfile, alongPath).normalize_or_zero();
+            mesh.push_vertex(position, normal, Vec2::new(normalized(&profileLengths, j), normalized(&pathLengths, i)));
+        }
+    }
+    mesh.push_grid_indices(0, rows, segments + 1);
+
+    if closed && options.caps {
+        let triangles = triangulatePolygon(profile);
+        let bounds = Aabb2::from_points(profile.iter().copied()).unwrap();
+        let size = bounds.size();
+        let capUv = |p: Point2| Vec2::new(
+            if size.x > 0.0 { (p.x - bounds.min.x) / size.x } else { 0.0 },
+            if size.y > 0.0 { (p.y - bounds.min.y) / size.y } else { 0.0 }
+        );
+
+        // Counterclockwise in the profile plane faces along the tangent, so the start cap is flipped
+        for (ring, frame, flip) in [(&rings[0], &frames[0], true), (&rings[segments], &frames[segments], false)] {
+            let normal = if flip { -frame.tangent } else { frame.tangent };
+            let first = mesh.vertex_count() as u32;
+            for (position, p) in ring.iter().zip(profile) {
+                mesh.push_vertex(*position, normal, capUv(*p));
+            }
+            for [a, b, c] in &triangles {
+                let (b, c) = if flip { (c, b) } else { (b, c) };
+                mesh.indices.extend_from_slice(&[first + *a as u32, first + *b as u32, first + *c as u32]);
+            }
+        }
+    }
+    mesh
+}
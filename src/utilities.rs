@@ -1,10 +1,13 @@
 use crate::point::{Point2, Point3};
-use crate::vector::{Vec2, Vec3, Vec4, InnerSpace};
+use crate::vector::{Vec2, Vec3, InnerSpace, VectorSpace};
+use crate::bezier::{Bezier2, Bezier3};
+use crate::approx::ApproxEq;
 
 // ##################################################
 // #                   CONSTANTS                    #
 // ##################################################
 
+#[allow(dead_code)]
 static facVals: [i64; 21] = [
     1,1,2,6,24,120,720,5040,40320,362880,3628800,
     39916800,479001600,6227020800,87178291200,1307674368000,
@@ -12,8 +15,9 @@ static facVals: [i64; 21] = [
     121645100408832000,2432902008176640000
 ]; 
 
+#[allow(dead_code)]
 static invFac: [f32; 6] = [
-    1.0,1.0,0.5,0.1666666666666666667,0.04166666666666666667,0.00833333333333333334
+    1.0,1.0,0.5,0.16666667,0.041666668,0.008333334
 ];
 
 static PI: f32 = std::f32::consts::PI;
@@ -38,11 +42,11 @@ pub fn ln(x: f32) -> f32 {
 }
 
 pub fn log(x: f32) -> f32 {
-    ln(x)/2.30258509299
+    ln(x)/std::f32::consts::LN_10
 }
 
 pub fn log2(x: f32) -> f32 {
-    ln(x)/0.69314718056
+    ln(x)/std::f32::consts::LN_2
 }
 
 pub fn sin(x: f32) -> f32 {
@@ -97,6 +101,40 @@ pub fn pointDerivative(func: fn(f32) -> f32, x: f32, h: f32) -> f32 {
     (func(x+h) - func(x-h))/(2.0 * h)
 }
 
+/// Solves the square linear system `A·x = b` by Gaussian elimination with partial pivoting.
+/// The right-hand side may hold vectors, which solves one system per component at once.
+/// Returns `None` if the matrix is singular.
+pub(crate) fn gaussianElimination<T: InnerSpace>(mut a: Vec<Vec<f32>>, mut b: Vec<T>) -> Option<Vec<T>> {
+    let n = b.len();
+    for j in 0..n {
+        let pivot = (j..n).max_by(|&p, &q| a[p][j].abs().total_cmp(&a[q][j].abs()))?;
+        if a[pivot][j].abs() < 1e-12 {
+            return None;
+        }
+        a.swap(j, pivot);
+        b.swap(j, pivot);
+
+        let pivotRow = a[j].clone();
+        for i in j + 1..n {
+            let factor = a[i][j] / pivotRow[j];
+            for (entry, pivotEntry) in a[i][j..].iter_mut().zip(&pivotRow[j..]) {
+                *entry -= factor * pivotEntry;
+            }
+            b[i] = b[i] - b[j] * factor;
+        }
+    }
+
+    let mut x = b.clone();
+    for i in (0..n).rev() {
+        let mut sum = b[i];
+        for k in i + 1..n {
+            sum = sum - x[k] * a[i][k];
+        }
+        x[i] = sum * (1.0 / a[i][i]);
+    }
+    Some(x)
+}
+
 // ##################################################
 // #                 INTERPOLATION                  #
 // ##################################################
@@ -114,13 +152,7 @@ pub fn lerp2(a: Vec2, b: Vec2, t: f32) -> Vec2 {
 }
 
 pub fn invLerp2(a: Vec2, b: Vec2, value: Vec2) -> Option<f32> {
-    let tx = (value.x - a.x) / (b.x - a.x);
-    let ty = (value.y - a.y) / (b.y - a.y);
-
-    if tx == ty {
-        return Some(tx);
-    }
-    None
+    invLerpComponents(&[(a.x, b.x, value.x), (a.y, b.y, value.y)])
 }
 
 pub fn lerp3(a: Vec3, b: Vec3, t: f32) -> Vec3 {
@@ -128,14 +160,29 @@ pub fn lerp3(a: Vec3, b: Vec3, t: f32) -> Vec3 {
 }
 
 pub fn invLerp3(a: Vec3, b: Vec3, value: Vec3) -> Option<f32> {
-    let tx = (value.x - a.x)/(b.x - a.x);
-    let ty = (value.y - a.y)/(b.y - a.y);
-    let tz = (value.z - a.z)/(b.z - a.z);
-
-    if (tx == ty) && (ty == tz) {
-        return Some(tx);
+    invLerpComponents(&[(a.x, b.x, value.x), (a.y, b.y, value.y), (a.z, b.z, value.z)])
+}
+
+/// Shared `t` of the per-component inverse lerps, or `None` if the value is not on the line through `a` and `b`.
+/// Components where `a` and `b` coincide only require the value to match them.
+fn invLerpComponents(components: &[(f32, f32, f32)]) -> Option<f32> {
+    let mut result: Option<f32> = None;
+    for &(a, b, value) in components {
+        if a.approx_eq(&b) {
+            if !value.approx_eq(&a) {
+                return None;
+            }
+            continue;
+        }
+
+        let t = invLerp(a, b, value);
+        match result {
+            Some(prev) if !prev.approx_eq(&t) => return None,
+            Some(_) => {}
+            None => result = Some(t)
+        }
     }
-    None
+    result
 }
 
 // ##################################################
@@ -176,6 +223,430 @@ pub fn bezier3(p0: Point3, p1: Point3, p2: Point3, p3: Point3, t: f32) -> Point3
 // #                    SPLINES                     #
 // ##################################################
 
+/// Knot spacing of a Catmull-Rom spline, i.e. the exponent `α` in `tᵢ₊₁ = tᵢ + |Pᵢ₊₁ - Pᵢ|^α`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CatmullRomParameterization {
+    /// `α = 0`, the classic spline, which can overshoot and form cusps
+    Uniform,
+    /// `α = 0.5`, never forms cusps or self-intersections within a segment
+    Centripetal,
+    /// `α = 1`, follows the control polygon most tightly
+    Chordal
+}
+
+impl CatmullRomParameterization {
+    pub fn alpha(&self) -> f32 {
+        match self {
+            CatmullRomParameterization::Uniform => 0.0,
+            CatmullRomParameterization::Centripetal => 0.5,
+            CatmullRomParameterization::Chordal => 1.0
+        }
+    }
+}
+
+/// Catmull-Rom spline interpolating its control points.
+/// The spline is parameterized over `t ∈ [0, 1]`, every segment taking an equal share of that range.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CatmullRom<T> {
+    points: Vec<T>,
+    parameterization: CatmullRomParameterization,
+    closed: bool
+}
+
+impl<T: InnerSpace> CatmullRom<T> {
+    /// Open spline from the first to the last point. Panics with fewer than two points.
+    pub fn new(points: Vec<T>, parameterization: CatmullRomParameterization) -> Self {
+        assert!(points.len() >= 2, "A Catmull-Rom spline needs at least two points!");
+        Self { points, parameterization, closed: false }
+    }
+
+    /// Closed loop that returns from the last point to the first. Panics with fewer than two points.
+    pub fn closed(points: Vec<T>, parameterization: CatmullRomParameterization) -> Self {
+        assert!(points.len() >= 2, "A Catmull-Rom spline needs at least two points!");
+        Self { points, parameterization, closed: true }
+    }
+
+    pub fn points(&self) -> &[T] {
+        &self.points
+    }
+
+    pub fn parameterization(&self) -> CatmullRomParameterization {
+        self.parameterization
+    }
+
+    pub fn is_closed(&self) -> bool {
+        self.closed
+    }
+
+    pub fn segment_count(&self) -> usize {
+        if self.closed { self.points.len() } else { self.points.len() - 1 }
+    }
+
+    /// Control point `i`, wrapping around for closed splines and
+    /// mirroring the neighbouring points past the ends of open ones
+    fn point(&self, i: isize) -> T {
+        let n = self.points.len() as isize;
+        if self.closed {
+            return self.points[i.rem_euclid(n) as usize];
+        }
+        match i {
+            _ if i < 0 => self.points[0] * 2.0 - self.points[1],
+            _ if i >= n => self.points[(n - 1) as usize] * 2.0 - self.points[(n - 2) as usize],
+            _ => self.points[i as usize]
+        }
+    }
+
+    /// Cubic Bézier control points of segment `i`, which runs from point `i` to point `i + 1`.
+    /// Uses the Barry-Goldman tangents of the non-uniform spline.
+    pub fn segment(&self, i: usize) -> [T; 4] {
+        let i = i as isize;
+        let (p0, p1, p2, p3) = (self.point(i - 1), self.point(i), self.point(i + 1), self.point(i + 2));
+        let alpha = self.parameterization.alpha();
+        let knotSpacing = |a: T, b: T| {
+            let dt = (b - a).length_squared().powf(0.5 * alpha);
+            if dt < 1e-6 { 1.0 } else { dt }
+        };
+        let (dt0, dt1, dt2) = (knotSpacing(p0, p1), knotSpacing(p1, p2), knotSpacing(p2, p3));
+
+        let m1 = ((p1 - p0) * (1.0 / dt0) - (p2 - p0) * (1.0 / (dt0 + dt1)) + (p2 - p1) * (1.0 / dt1)) * dt1;
+        let m2 = ((p2 - p1) * (1.0 / dt1) - (p3 - p1) * (1.0 / (dt1 + dt2)) + (p3 - p2) * (1.0 / dt2)) * dt1;
+        [p1, p1 + m1 * (1.0 / 3.0), p2 - m2 * (1.0 / 3.0), p2]
+    }
+
+    /// Segment index and local parameter for the global parameter `t`
+    pub(crate) fn locate(&self, t: f32) -> (usize, f32) {
+        let count = self.segment_count();
+        let scaled = t.clamp(0.0, 1.0) * count as f32;
+        let i = (scaled.floor() as usize).min(count - 1);
+        (i, scaled - i as f32)
+    }
+
+    pub fn eval(&self, t: f32) -> T {
+        let (i, local) = self.locate(t);
+        self.eval_segment(i, local)
+    }
+
+    /// Derivative with respect to the global parameter `t`
+    pub fn velocity(&self, t: f32) -> T {
+        let (i, local) = self.locate(t);
+        self.segment_velocity(i, local) * self.segment_count() as f32
+    }
+
+    /// Unit tangent at `t`, or zero where the spline is degenerate
+    pub fn tangent(&self, t: f32) -> T {
+        self.velocity(t).normalize_or_zero()
+    }
+
+    /// Evaluates segment `i` at the local parameter `t ∈ [0, 1]`
+    pub fn eval_segment(&self, i: usize, t: f32) -> T {
+        let [p0, p1, p2, p3] = self.segment(i);
+        let s = 1.0 - t;
+        p0 * (s * s * s) + p1 * (3.0 * s * s * t) + p2 * (3.0 * s * t * t) + p3 * (t * t * t)
+    }
+
+    /// Derivative of segment `i` with respect to its local parameter
+    pub fn segment_velocity(&self, i: usize, t: f32) -> T {
+        let [p0, p1, p2, p3] = self.segment(i);
+        let s = 1.0 - t;
+        ((p1 - p0) * (s * s) + (p2 - p1) * (2.0 * s * t) + (p3 - p2) * (t * t)) * 3.0
+    }
+}
+
+/// Tension, continuity and bias of a Kochanek-Bartels key. All zero gives a Catmull-Rom spline.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct KochanekBartels {
+    /// Shortens (positive) or lengthens (negative) the tangents
+    pub tension: f32,
+    /// Negative values give sharp corners, positive values give bulges
+    pub continuity: f32,
+    /// Shifts the tangent towards the previous (positive) or the next (negative) point
+    pub bias: f32
+}
+
+impl KochanekBartels {
+    pub const fn new(tension: f32, continuity: f32, bias: f32) -> Self {
+        KochanekBartels { tension, continuity, bias }
+    }
+
+    /// Incoming and outgoing tangent at `current` given its neighbours
+    pub fn tangents<T: VectorSpace>(&self, previous: T, current: T, next: T) -> (T, T) {
+        let (t, c, b) = (self.tension, self.continuity, self.bias);
+        let back = current - previous;
+        let ahead = next - current;
+        let incoming = back * ((1.0 - t) * (1.0 + b) * (1.0 - c) * 0.5) + ahead * ((1.0 - t) * (1.0 - b) * (1.0 + c) * 0.5);
+        let outgoing = back * ((1.0 - t) * (1.0 + b) * (1.0 + c) * 0.5) + ahead * ((1.0 - t) * (1.0 - b) * (1.0 - c) * 0.5);
+        (incoming, outgoing)
+    }
+}
+
+/// Cubic Hermite spline through its points with explicit incoming and outgoing tangents per point.
+/// The spline is parameterized over `t ∈ [0, 1]`, every segment taking an equal share of that range,
+/// and tangents are derivatives with respect to the parameter of their segment.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HermiteSpline<T> {
+    points: Vec<T>,
+    inTangents: Vec<T>,
+    outTangents: Vec<T>
+}
+
+impl<T: VectorSpace> HermiteSpline<T> {
+    /// Panics if there are fewer than two points or the tangent counts do not match the points.
+    pub fn new(points: Vec<T>, in_tangents: Vec<T>, out_tangents: Vec<T>) -> Self {
+        assert!(points.len() >= 2, "A Hermite spline needs at least two points!");
+        assert!(in_tangents.len() == points.len() && out_tangents.len() == points.len(), "Every point of a Hermite spline needs an incoming and an outgoing tangent!");
+        Self { points, inTangents: in_tangents, outTangents: out_tangents }
+    }
+
+    /// Kochanek-Bartels spline with the same tension, continuity and bias at every point.
+    /// The end points mirror their neighbour to get a tangent.
+    pub fn kochanek_bartels(points: Vec<T>, params: KochanekBartels) -> Self {
+        let keys = vec![params; points.len()];
+        Self::kochanek_bartels_per_key(points, &keys)
+    }
+
+    /// Kochanek-Bartels spline with individual tension, continuity and bias for every point
+    pub fn kochanek_bartels_per_key(points: Vec<T>, keys: &[KochanekBartels]) -> Self {
+        assert!(points.len() >= 2, "A Hermite spline needs at least two points!");
+        assert_eq!(points.len(), keys.len(), "Every point needs exactly one set of Kochanek-Bartels parameters!");
+        let n = points.len();
+        let (inTangents, outTangents) = (0..n).map(|i| {
+            let previous = if i == 0 { points[0] * 2.0 - points[1] } else { points[i - 1] };
+            let next = if i == n - 1 { points[n - 1] * 2.0 - points[n - 2] } else { points[i + 1] };
+            keys[i].tangents(previous, points[i], next)
+        }).unzip();
+        Self { points, inTangents, outTangents }
+    }
+
+    pub fn points(&self) -> &[T] {
+        &self.points
+    }
+
+    pub fn in_tangents(&self) -> &[T] {
+        &self.inTangents
+    }
+
+    pub fn out_tangents(&self) -> &[T] {
+        &self.outTangents
+    }
+
+    pub fn segment_count(&self) -> usize {
+        self.points.len() - 1
+    }
+
+    /// Cubic Bézier control points of segment `i`, which runs from point `i` to point `i + 1`
+    pub fn segment(&self, i: usize) -> [T; 4] {
+        let (p0, p1) = (self.points[i], self.points[i + 1]);
+        [p0, p0 + self.outTangents[i] * (1.0 / 3.0), p1 - self.inTangents[i + 1] * (1.0 / 3.0), p1]
+    }
+
+    /// Segment index and local parameter for the global parameter `t`
+    pub(crate) fn locate(&self, t: f32) -> (usize, f32) {
+        let count = self.segment_count();
+        let scaled = t.clamp(0.0, 1.0) * count as f32;
+        let i = (scaled.floor() as usize).min(count - 1);
+        (i, scaled - i as f32)
+    }
+
+    pub fn eval(&self, t: f32) -> T {
+        let (i, local) = self.locate(t);
+        self.eval_segment(i, local)
+    }
+
+    /// Derivative with respect to the global parameter `t`
+    pub fn velocity(&self, t: f32) -> T {
+        let (i, local) = self.locate(t);
+        self.segment_velocity(i, local) * self.segment_count() as f32
+    }
+
+    /// Evaluates segment `i` at the local parameter `t ∈ [0, 1]` with the Hermite basis functions
+    pub fn eval_segment(&self, i: usize, t: f32) -> T {
+        let (t2, t3) = (t * t, t * t * t);
+        self.points[i] * (2.0 * t3 - 3.0 * t2 + 1.0)
+            + self.outTangents[i] * (t3 - 2.0 * t2 + t)
+            + self.points[i + 1] * (-2.0 * t3 + 3.0 * t2)
+            + self.inTangents[i + 1] * (t3 - t2)
+    }
+
+    /// Derivative of segment `i` with respect to its local parameter
+    pub fn segment_velocity(&self, i: usize, t: f32) -> T {
+        let t2 = t * t;
+        (self.points[i + 1] - self.points[i]) * (6.0 * t - 6.0 * t2)
+            + self.outTangents[i] * (3.0 * t2 - 4.0 * t + 1.0)
+            + self.inTangents[i + 1] * (3.0 * t2 - 2.0 * t)
+    }
+}
+
+impl HermiteSpline<Vec2> {
+    /// Converts every segment into a cubic `Bezier2`
+    pub fn to_beziers(&self) -> Vec<Bezier2> {
+        (0..self.segment_count()).map(|i| Bezier2::new(self.segment(i).to_vec())).collect()
+    }
+}
+
+impl HermiteSpline<Vec3> {
+    /// Converts every segment into a cubic `Bezier3`
+    pub fn to_beziers(&self) -> Vec<Bezier3> {
+        (0..self.segment_count()).map(|i| Bezier3::new(self.segment(i).to_vec())).collect()
+    }
+}
+
+/// Piecewise cubic interpolation through tabulated `(x, y)` data.
+/// Every piece is stored by the values and slopes at its ends, so all three constructors share evaluation.
+/// Outside of the data range the spline holds the first or last value.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CubicSpline {
+    xs: Vec<f32>,
+    ys: Vec<f32>,
+    slopes: Vec<f32>
+}
+
+/// Solves the tridiagonal system with sub-diagonal `a`, diagonal `b`, super-diagonal `c` and right-hand side `d`
+fn solveTridiagonal(a: &[f32], b: &[f32], c: &[f32], d: &[f32]) -> Vec<f32> {
+    let n = d.len();
+    let mut cPrime = vec![0.0; n];
+    let mut dPrime = vec![0.0; n];
+    cPrime[0] = c[0] / b[0];
+    dPrime[0] = d[0] / b[0];
+    for i in 1..n {
+        let denominator = b[i] - a[i] * cPrime[i - 1];
+        cPrime[i] = c[i] / denominator;
+        dPrime[i] = (d[i] - a[i] * dPrime[i - 1]) / denominator;
+    }
+
+    let mut x = vec![0.0; n];
+    x[n - 1] = dPrime[n - 1];
+    for i in (0..n - 1).rev() {
+        x[i] = dPrime[i] - cPrime[i] * x[i + 1];
+    }
+    x
+}
+
+impl CubicSpline {
+    fn split(points: &[(f32, f32)]) -> (Vec<f32>, Vec<f32>) {
+        assert!(points.len() >= 2, "A cubic spline needs at least two data points!");
+        assert!(points.windows(2).all(|w| w[0].0 < w[1].0), "The x values of a cubic spline must be strictly increasing!");
+        points.iter().copied().unzip()
+    }
+
+    /// C² spline with the slopes given at both ends, or with zero second derivative there for the natural spline when `endSlopes` is `None`
+    fn withEndSlopes(points: &[(f32, f32)], endSlopes: Option<(f32, f32)>) -> Self {
+        let (xs, ys) = Self::split(points);
+        let n = xs.len();
+        let h: Vec<f32> = xs.windows(2).map(|w| w[1] - w[0]).collect();
+        let delta: Vec<f32> = (0..n - 1).map(|i| (ys[i + 1] - ys[i]) / h[i]).collect();
+
+        // Second derivatives M at the data points
+        let (mut a, mut b, mut c, mut d) = (vec![0.0; n], vec![0.0; n], vec![0.0; n], vec![0.0; n]);
+        for i in 1..n - 1 {
+            a[i] = h[i - 1];
+            b[i] = 2.0 * (h[i - 1] + h[i]);
+            c[i] = h[i];
+            d[i] = 6.0 * (delta[i] - delta[i - 1]);
+        }
+        match endSlopes {
+            None => {
+                b[0] = 1.0;
+                b[n - 1] = 1.0;
+            }
+            Some((start, end)) => {
+                b[0] = 2.0 * h[0];
+                c[0] = h[0];
+                d[0] = 6.0 * (delta[0] - start);
+                a[n - 1] = h[n - 2];
+                b[n - 1] = 2.0 * h[n - 2];
+                d[n - 1] = 6.0 * (end - delta[n - 2]);
+            }
+        }
+        let m = solveTridiagonal(&a, &b, &c, &d);
+
+        let mut slopes: Vec<f32> = (0..n - 1).map(|i| delta[i] - h[i] * (2.0 * m[i] + m[i + 1]) / 6.0).collect();
+        slopes.push(delta[n - 2] + h[n - 2] * (m[n - 2] + 2.0 * m[n - 1]) / 6.0);
+        Self { xs, ys, slopes }
+    }
+
+    /// Natural cubic spline, which has zero curvature at both ends.
+    /// Panics with fewer than two points or x values that are not strictly increasing.
+    pub fn natural(points: &[(f32, f32)]) -> Self {
+        Self::withEndSlopes(points, None)
+    }
+
+    /// Cubic spline with the given slopes `dy/dx` at the first and last point
+    pub fn clamped(points: &[(f32, f32)], start_slope: f32, end_slope: f32) -> Self {
+        Self::withEndSlopes(points, Some((start_slope, end_slope)))
+    }
+
+    /// Fritsch-Carlson monotone cubic interpolation, which never overshoots the data.
+    /// Monotonic data stays monotonic and flat parts stay flat, at the cost of only C¹ continuity.
+    pub fn monotone(points: &[(f32, f32)]) -> Self {
+        let (xs, ys) = Self::split(points);
+        let n = xs.len();
+        let delta: Vec<f32> = (0..n - 1).map(|i| (ys[i + 1] - ys[i]) / (xs[i + 1] - xs[i])).collect();
+
+        let mut slopes = vec![0.0; n];
+        slopes[0] = delta[0];
+        slopes[n - 1] = delta[n - 2];
+        for i in 1..n - 1 {
+            if delta[i - 1] * delta[i] > 0.0 {
+                slopes[i] = 0.5 * (delta[i - 1] + delta[i]);
+            }
+        }
+
+        for i in 0..n - 1 {
+            if delta[i] == 0.0 {
+                slopes[i] = 0.0;
+                slopes[i + 1] = 0.0;
+                continue;
+            }
+            let alpha = slopes[i] / delta[i];
+            let beta = slopes[i + 1] / delta[i];
+            let magnitude = alpha * alpha + beta * beta;
+            if magnitude > 9.0 {
+                let tau = 3.0 / magnitude.sqrt();
+                slopes[i] = tau * alpha * delta[i];
+                slopes[i + 1] = tau * beta * delta[i];
+            }
+        }
+        Self { xs, ys, slopes }
+    }
+
+    /// Interval `i` with `xs[i] <= x <= xs[i + 1]`, its width and the local parameter
+    fn locate(&self, x: f32) -> (usize, f32, f32) {
+        let last = self.xs.len() - 1;
+        let i = self.xs.partition_point(|v| *v <= x).clamp(1, last) - 1;
+        let h = self.xs[i + 1] - self.xs[i];
+        (i, h, (x - self.xs[i]) / h)
+    }
+
+    pub fn eval(&self, x: f32) -> f32 {
+        let last = self.xs.len() - 1;
+        if x <= self.xs[0] {
+            return self.ys[0];
+        }
+        if x >= self.xs[last] {
+            return self.ys[last];
+        }
+
+        let (i, h, t) = self.locate(x);
+        let (t2, t3) = (t * t, t * t * t);
+        self.ys[i] * (2.0 * t3 - 3.0 * t2 + 1.0)
+            + self.slopes[i] * h * (t3 - 2.0 * t2 + t)
+            + self.ys[i + 1] * (-2.0 * t3 + 3.0 * t2)
+            + self.slopes[i + 1] * h * (t3 - t2)
+    }
+
+    /// Slope `dy/dx` at `x`, zero outside of the data range
+    pub fn derivative(&self, x: f32) -> f32 {
+        if x < self.xs[0] || x > self.xs[self.xs.len() - 1] {
+            return 0.0;
+        }
+
+        let (i, h, t) = self.locate(x);
+        let t2 = t * t;
+        (self.ys[i + 1] - self.ys[i]) / h * (6.0 * t - 6.0 * t2)
+            + self.slopes[i] * (3.0 * t2 - 4.0 * t + 1.0)
+            + self.slopes[i + 1] * (3.0 * t2 - 2.0 * t)
+    }
+}
 
 // ##################################################
 // #                EASING FUNCTIONS                #
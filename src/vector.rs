@@ -1,11 +1,100 @@
-use std::ops::{Add, Sub, Mul, Div};
-use crate::point::{Point2, Point3};
-use crate::utilities::acos;
+use std::ops::{Add, Sub, Mul, Div, Neg};
+use crate::point::{Point2, Point3, Point4};
+use crate::mask::{BVec2, BVec3, BVec4};
 
-pub trait InnerSpace {
+/// Values that can be added and scaled, which is all that interpolation needs.
+/// Implemented for `f32` and every vector type.
+pub trait VectorSpace: Sized + Copy + Add<Output = Self> + Sub<Output = Self> + Mul<f32, Output = Self> {}
+
+impl<T: Sized + Copy + Add<Output = T> + Sub<Output = T> + Mul<f32, Output = T>> VectorSpace for T {}
+
+pub trait InnerSpace: VectorSpace {
     fn dot(&self, other: &Self) -> f32;
     fn dist(&self, other: &Self) -> f32;
     fn vAngle(&self, other: &Self) -> f32;
+
+    fn length_squared(&self) -> f32 {
+        self.dot(self)
+    }
+
+    fn length_recip(&self) -> f32 {
+        1.0 / self.length_squared().sqrt()
+    }
+
+    /// Whether the length of the vector is 1 up to a small tolerance
+    fn is_normalized(&self) -> bool {
+        (self.length_squared() - 1.0).abs() <= 2e-4
+    }
+
+    /// Normalized vector, or `None` if the vector is zero, too short or not finite
+    fn try_normalize(&self) -> Option<Self> {
+        let recip = self.length_recip();
+        if recip.is_finite() && recip > 0.0 {
+            Some(*self * recip)
+        } else {
+            None
+        }
+    }
+
+    fn normalize_or(&self, fallback: Self) -> Self {
+        self.try_normalize().unwrap_or(fallback)
+    }
+
+    fn normalize_or_zero(&self) -> Self {
+        self.try_normalize().unwrap_or(*self * 0.0)
+    }
+
+    /// Scales the vector so its length lies in `[min, max]`. A zero vector has no direction to scale along and stays zero.
+    fn clamp_length(&self, min: f32, max: f32) -> Self {
+        debug_assert!(min <= max, "clamp_length: min must not be greater than max");
+        let lengthSq = self.length_squared();
+        if lengthSq == 0.0 {
+            *self
+        } else if lengthSq < min * min {
+            *self * (min / lengthSq.sqrt())
+        } else if lengthSq > max * max {
+            *self * (max / lengthSq.sqrt())
+        } else {
+            *self
+        }
+    }
+
+    /// Unsigned angle between two vectors in `[0, π]`.
+    /// Uses Kahan's atan2 formulation, which stays accurate for nearly parallel and antiparallel vectors.
+    fn angle_between(&self, other: &Self) -> f32 {
+        let a = *self * other.dot(other).sqrt();
+        let b = *other * self.dot(self).sqrt();
+        let diff = a - b;
+        let sum = a + b;
+        2.0 * diff.dot(&diff).sqrt().atan2(sum.dot(&sum).sqrt())
+    }
+
+    /// Reflects the vector on the plane (or line) with the given unit `normal`
+    fn reflect(&self, normal: &Self) -> Self {
+        *self - *normal * (2.0 * self.dot(normal))
+    }
+
+    /// Refracts the unit vector through a surface with the given unit `normal`,
+    /// where `eta` is the ratio of the refractive indices.
+    /// Returns `None` on total internal reflection.
+    fn refract(&self, normal: &Self, eta: f32) -> Option<Self> {
+        let cosI = self.dot(normal);
+        let k = 1.0 - eta * eta * (1.0 - cosI * cosI);
+        if k < 0.0 {
+            return None;
+        }
+        Some(*self * eta - *normal * (eta * cosI + k.sqrt()))
+    }
+
+    /// Component of the vector parallel to `other`
+    fn project_onto(&self, other: &Self) -> Self {
+        *other * (self.dot(other) / other.dot(other))
+    }
+
+    /// Component of the vector perpendicular to `other`
+    fn reject_from(&self, other: &Self) -> Self {
+        *self - self.project_onto(other)
+    }
 }
 
 // ##################################################
@@ -14,7 +103,7 @@ pub trait InnerSpace {
 
 /// Representation of a 2D Vector
 #[repr(C)]
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Vec2 {
     pub x: f32,
@@ -38,25 +127,28 @@ impl Vec2 {
         (self.x * self.x + self.y * self.y).sqrt()
     }
     
+    /// Normalized vector. Vectors whose length is zero or not finite fail a debug assertion and produce NaNs or zeros otherwise,
+    /// use `try_normalize` or `normalize_or_zero` if the vector may be degenerate.
     pub fn normalize(&self) -> Self {
-        let factor = 1.0/self.length();
+        let length = self.length();
+        debug_assert!(length.is_finite() && length > 0.0, "normalize called on a zero or non-finite vector: {:?}", self);
+        let factor = 1.0/length;
         Vec2 { x: factor * self.x, y: factor * self.y }
     }
 
-    pub fn xx(&self) -> Vec2 {
-        Vec2 { x: self.x, y: self.x }
+    /// The vector rotated counterclockwise by 90°
+    pub fn perp(&self) -> Self {
+        Vec2 { x: -self.y, y: self.x }
     }
 
-    pub fn xy(&self) -> Vec2 {
-        Vec2 { x: self.x, y: self.y }
+    /// The z component of the 3D cross product of both vectors
+    pub fn perp_dot(&self, other: &Self) -> f32 {
+        self.x * other.y - self.y * other.x
     }
 
-    pub fn yx(&self) -> Vec2 {
-        Vec2 { x: self.y, y: self.x }
-    }
-
-    pub fn yy(&self) -> Vec2 {
-        Vec2 { x: self.y, y: self.y }
+    /// Signed angle in `(-π, π]` needed to rotate the vector onto `other`, counterclockwise being positive
+    pub fn signed_angle(&self, other: &Self) -> f32 {
+        self.perp_dot(other).atan2(self.dot(other))
     }
 }
 
@@ -84,13 +176,29 @@ impl Mul<f32> for Vec2 {
     }
 }
 
+impl Div<f32> for Vec2 {
+    type Output = Vec2;
+
+    fn div(self, other: f32) -> Vec2 {
+        Vec2 { x: self.x / other, y: self.y / other }
+    }
+}
+
+impl Neg for Vec2 {
+    type Output = Vec2;
+
+    fn neg(self) -> Vec2 {
+        Vec2 { x: -self.x, y: -self.y }
+    }
+}
+
 // ##################################################
 // #                   VECTOR 3D                    #
 // ##################################################
 
 /// Representation of a 3D Vector
 #[repr(C)]
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Vec3 {
     pub x: f32,
@@ -115,10 +223,62 @@ impl Vec3 {
         (self.x * self.x + self.y * self.y + self.z * self.z).sqrt()
     }
 
+    /// Normalized vector. Vectors whose length is zero or not finite fail a debug assertion and produce NaNs or zeros otherwise,
+    /// use `try_normalize` or `normalize_or_zero` if the vector may be degenerate.
     pub fn normalize(&self) -> Self {
-        let factor = 1.0/self.length();
+        let length = self.length();
+        debug_assert!(length.is_finite() && length > 0.0, "normalize called on a zero or non-finite vector: {:?}", self);
+        let factor = 1.0/length;
         Vec3 { x: factor * self.x, y: factor * self.y, z: factor * self.z }
     }
+
+    /// Some vector orthogonal to this one, not normalized
+    pub fn any_orthogonal_vector(&self) -> Self {
+        if self.x.abs() > self.y.abs() {
+            Vec3 { x: -self.z, y: 0.0, z: self.x }
+        } else {
+            Vec3 { x: 0.0, y: self.z, z: -self.y }
+        }
+    }
+
+    /// Two unit vectors that form a right-handed orthonormal basis together with this unit vector.
+    /// Uses the branchless construction from Duff et al., "Building an Orthonormal Basis, Revisited".
+    pub fn any_orthonormal_pair(&self) -> (Self, Self) {
+        let sign = 1.0_f32.copysign(self.z);
+        let a = -1.0 / (sign + self.z);
+        let b = self.x * self.y * a;
+        (
+            Vec3 { x: 1.0 + sign * self.x * self.x * a, y: sign * b, z: -sign * self.x },
+            Vec3 { x: b, y: sign + self.y * self.y * a, z: -self.y }
+        )
+    }
+
+    /// Spherical linear interpolation between two directions.
+    /// The direction moves along the great circle at constant angular speed while the length is interpolated linearly.
+    pub fn slerp(&self, other: &Self, t: f32) -> Self {
+        let (lenA, lenB) = (self.length(), other.length());
+        let a = *self / lenA;
+        let b = *other / lenB;
+        let theta = a.angle_between(&b);
+        let length = lenA + (lenB - lenA) * t;
+
+        if theta < 1e-4 {
+            return (a + (b - a) * t).normalize() * length;
+        }
+
+        let sinTheta = theta.sin();
+        if sinTheta < 1e-4 {
+            // Antiparallel directions: any great circle works, so rotate through an arbitrary perpendicular axis
+            let helper = if a.x.abs() < 0.9 { Vec3::new(1.0, 0.0, 0.0) } else { Vec3::new(0.0, 1.0, 0.0) };
+            let perp = cross(a, helper).normalize();
+            let angle = theta * t;
+            return (a * angle.cos() + perp * angle.sin()) * length;
+        }
+
+        let wA = ((1.0 - t) * theta).sin() / sinTheta;
+        let wB = (t * theta).sin() / sinTheta;
+        (a * wA + b * wB) * length
+    }
 }
 
 impl Add<Vec3> for Vec3 {
@@ -146,13 +306,29 @@ impl Mul<f32> for Vec3 {
     }
 }
 
+impl Div<f32> for Vec3 {
+    type Output = Vec3;
+
+    fn div(self, other: f32) -> Vec3 {
+        Vec3 { x: self.x / other, y: self.y / other, z: self.z / other }
+    }
+}
+
+impl Neg for Vec3 {
+    type Output = Vec3;
+
+    fn neg(self) -> Vec3 {
+        Vec3 { x: -self.x, y: -self.y, z: -self.z }
+    }
+}
+
 // ##################################################
 // #                   VECTOR 4D                    #
 // ##################################################
 
 /// Representation of a 4D Vector
 #[repr(C)]
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Vec4 {
     pub x: f32,
@@ -170,12 +346,20 @@ impl Vec4 {
         Vec4 { x, y, z, w }
     }
 
+    pub fn fromPoint(p: Point4) -> Self {
+        Vec4 { x: p.x, y: p.y, z: p.z, w: p.w }
+    }
+
     pub fn length(&self) -> f32 {
         (self.x * self.x + self.y * self.y + self.z * self.z + self.w * self.w).sqrt()
     }
 
+    /// Normalized vector. Vectors whose length is zero or not finite fail a debug assertion and produce NaNs or zeros otherwise,
+    /// use `try_normalize` or `normalize_or_zero` if the vector may be degenerate.
     pub fn normalize(&self) -> Self {
-        let factor = 1.0/self.length();
+        let length = self.length();
+        debug_assert!(length.is_finite() && length > 0.0, "normalize called on a zero or non-finite vector: {:?}", self);
+        let factor = 1.0/length;
         Vec4 { x: factor * self.x, y: factor * self.y, z: factor * self.z, w: factor * self.w }
     }
 }
@@ -204,6 +388,135 @@ impl Mul<f32> for Vec4 {
     }
 }
 
+impl Div<f32> for Vec4 {
+    type Output = Vec4;
+
+    fn div(self, other: f32) -> Vec4 {
+        Vec4 { x: self.x / other, y: self.y / other, z: self.z / other, w: self.w / other }
+    }
+}
+
+impl Neg for Vec4 {
+    type Output = Vec4;
+
+    fn neg(self) -> Vec4 {
+        Vec4 { x: -self.x, y: -self.y, z: -self.z, w: -self.w }
+    }
+}
+
+// ##################################################
+// #              COMPONENT-WISE MATH               #
+// ##################################################
+
+macro_rules! impl_componentwise {
+    ($name:ident, $mask:ident, $($field:ident),+) => {
+        impl $name {
+            pub const fn splat(value: f32) -> Self {
+                $name { $($field: value),+ }
+            }
+
+            pub fn min(&self, other: Self) -> Self {
+                $name { $($field: self.$field.min(other.$field)),+ }
+            }
+
+            pub fn max(&self, other: Self) -> Self {
+                $name { $($field: self.$field.max(other.$field)),+ }
+            }
+
+            /// Clamps every component between the matching components of `min` and `max`
+            pub fn clamp(&self, min: Self, max: Self) -> Self {
+                $name { $($field: crate::utilities::clamp(min.$field, max.$field, self.$field)),+ }
+            }
+
+            pub fn abs(&self) -> Self {
+                $name { $($field: self.$field.abs()),+ }
+            }
+
+            pub fn signum(&self) -> Self {
+                $name { $($field: self.$field.signum()),+ }
+            }
+
+            pub fn floor(&self) -> Self {
+                $name { $($field: self.$field.floor()),+ }
+            }
+
+            pub fn ceil(&self) -> Self {
+                $name { $($field: self.$field.ceil()),+ }
+            }
+
+            pub fn round(&self) -> Self {
+                $name { $($field: self.$field.round()),+ }
+            }
+
+            /// Fractional part of every component as `x - floor(x)`, so it is always in `[0, 1)`.
+            /// Tiny negative inputs would round up to exactly 1, so the result is capped at the largest float below 1.
+            pub fn fract(&self) -> Self {
+                $name { $($field: (self.$field - self.$field.floor()).min(1.0 - f32::EPSILON / 2.0)),+ }
+            }
+
+            pub fn powf(&self, n: f32) -> Self {
+                $name { $($field: self.$field.powf(n)),+ }
+            }
+
+            pub fn exp(&self) -> Self {
+                $name { $($field: self.$field.exp()),+ }
+            }
+
+            pub fn recip(&self) -> Self {
+                $name { $($field: self.$field.recip()),+ }
+            }
+
+            pub fn min_element(&self) -> f32 {
+                f32::INFINITY $(.min(self.$field))+
+            }
+
+            pub fn max_element(&self) -> f32 {
+                f32::NEG_INFINITY $(.max(self.$field))+
+            }
+
+            /// Takes the components of `a` where `mask` is `true` and those of `b` elsewhere
+            pub fn select(mask: $mask, a: Self, b: Self) -> Self {
+                $name { $($field: if mask.$field { a.$field } else { b.$field }),+ }
+            }
+
+            pub fn cmpeq(&self, other: Self) -> $mask {
+                $mask { $($field: self.$field == other.$field),+ }
+            }
+
+            pub fn cmpne(&self, other: Self) -> $mask {
+                $mask { $($field: self.$field != other.$field),+ }
+            }
+
+            pub fn cmplt(&self, other: Self) -> $mask {
+                $mask { $($field: self.$field < other.$field),+ }
+            }
+
+            pub fn cmple(&self, other: Self) -> $mask {
+                $mask { $($field: self.$field <= other.$field),+ }
+            }
+
+            pub fn cmpgt(&self, other: Self) -> $mask {
+                $mask { $($field: self.$field > other.$field),+ }
+            }
+
+            pub fn cmpge(&self, other: Self) -> $mask {
+                $mask { $($field: self.$field >= other.$field),+ }
+            }
+        }
+    };
+}
+
+impl_componentwise!(Vec2, BVec2, x, y);
+impl_componentwise!(Vec3, BVec3, x, y, z);
+impl_componentwise!(Vec4, BVec4, x, y, z, w);
+
+// ##################################################
+// #                   SWIZZLES                     #
+// ##################################################
+
+// Generated by build.rs: read swizzles like `v.zyx()` and write swizzles like `v.set_xz(..)`
+include!(concat!(env!("OUT_DIR"), "/swizzles.rs"));
+
 impl InnerSpace for Vec2 {
     fn dot(&self, other: &Self) -> f32 {
         self.x * other.x + self.y * other.y
@@ -214,7 +527,7 @@ impl InnerSpace for Vec2 {
     }
 
     fn vAngle(&self, other: &Self) -> f32 {
-        acos(dot(self,other)/(self.length() * other.length()))
+        self.angle_between(other)
     }
 }
 
@@ -228,7 +541,7 @@ impl InnerSpace for Vec3 {
     }
 
     fn vAngle(&self, other: &Self) -> f32 {
-        acos(dot(self,other)/(self.length() * other.length()))
+        self.angle_between(other)
     }
 }
 
@@ -242,7 +555,7 @@ impl InnerSpace for Vec4 {
     }
 
     fn vAngle(&self, other: &Self) -> f32 {
-        acos(dot(self,other)/(self.length() * other.length()))
+        self.angle_between(other)
     }
 }
 
@@ -255,7 +568,23 @@ pub fn dot<T: InnerSpace>(v1: &T, v2: &T) -> f32 {
 }
 
 pub fn cross(v1: Vec3, v2: Vec3) -> Vec3 {
-    Vec3 { x: v1.y * v2.z - v1.z * v2.y, y: v1.z * v2.x - v1.x * v2.z, z: v1.x * v2.y - v2.y * v1.x }
+    Vec3 { x: v1.y * v2.z - v1.z * v2.y, y: v1.z * v2.x - v1.x * v2.z, z: v1.x * v2.y - v1.y * v2.x }
+}
+
+/// Orthonormalizes the vectors in place with the modified Gram-Schmidt process.
+/// Vectors that are linearly dependent on the ones before them become zero. A vector counts as dependent when
+/// what remains after removing the earlier directions is at most `1e-4` times its original length, or `1e-4` for a zero vector.
+pub fn orthonormalize<T: InnerSpace>(vectors: &mut [T]) {
+    for i in 0..vectors.len() {
+        let original = vectors[i];
+        let mut v = original;
+        for basis in &vectors[..i] {
+            v = v - *basis * v.dot(basis);
+        }
+        let length = original.length_squared().sqrt();
+        let threshold = 1e-4 * if length > 0.0 { length } else { 1.0 };
+        vectors[i] = if v.length_squared().sqrt() <= threshold { v * 0.0 } else { v.normalize_or_zero() };
+    }
 }
 
 pub fn vDist<T: InnerSpace>(v1: &T, v2: &T) -> f32 {